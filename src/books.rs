@@ -0,0 +1,226 @@
+/// The books of the Bible in canonical (Protestant) order
+/// Names are lowercase to match the keys stored in `Bible::verses`
+///
+/// With the `apocrypha` feature enabled, the deuterocanonical books are interleaved in their
+/// Catholic positions, following the order of the New American Bible:
+/// Tobit and Judith after Nehemiah, 1 and 2 Maccabees after Esther, Wisdom and Sirach after
+/// Song of Solomon, and Baruch after Lamentations
+pub const CANONICAL_BOOKS: &[&str] = &[
+    // Old Testament
+    "genesis", "exodus", "leviticus", "numbers", "deuteronomy",
+    "joshua", "judges", "ruth", "1 samuel", "2 samuel",
+    "1 kings", "2 kings", "1 chronicles", "2 chronicles", "ezra",
+    "nehemiah",
+    #[cfg(feature = "apocrypha")] "tobit",
+    #[cfg(feature = "apocrypha")] "judith",
+    "esther",
+    #[cfg(feature = "apocrypha")] "1 maccabees",
+    #[cfg(feature = "apocrypha")] "2 maccabees",
+    "job", "psalms", "proverbs",
+    "ecclesiastes", "song of solomon",
+    #[cfg(feature = "apocrypha")] "wisdom",
+    #[cfg(feature = "apocrypha")] "sirach",
+    "isaiah", "jeremiah", "lamentations",
+    #[cfg(feature = "apocrypha")] "baruch",
+    "ezekiel", "daniel", "hosea", "joel", "amos",
+    "obadiah", "jonah", "micah", "nahum", "habakkuk",
+    "zephaniah", "haggai", "zechariah", "malachi",
+    // New Testament
+    "matthew", "mark", "luke", "john", "acts",
+    "romans", "1 corinthians", "2 corinthians", "galatians", "ephesians",
+    "philippians", "colossians", "1 thessalonians", "2 thessalonians", "1 timothy",
+    "2 timothy", "titus", "philemon", "hebrews", "james",
+    "1 peter", "2 peter", "1 john", "2 john", "3 john",
+    "jude", "revelation",
+];
+
+/// The two testaments of the Bible
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Testament {
+    /// Genesis through Malachi
+    Old,
+    /// Matthew through Revelation
+    New,
+}
+
+impl std::fmt::Display for Testament {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Old => write!(f, "Old Testament"),
+            Self::New => write!(f, "New Testament"),
+        }
+    }
+}
+
+/// Get the testament a book belongs to
+/// `book` is not case-sensitive
+/// Returns `None` if the book is not part of the canon (i.e. a custom book)
+/// # Example
+/// ```
+/// use bible_lib::books::{testament, Testament};
+///
+/// assert_eq!(testament("Genesis"), Some(Testament::Old));
+/// assert_eq!(testament("John"), Some(Testament::New));
+/// ```
+pub fn testament(book: &str) -> Option<Testament> {
+    let index = canonical_index(book)?;
+    let matthew = canonical_index("matthew")?;
+    if index < matthew {
+        Some(Testament::Old)
+    } else {
+        Some(Testament::New)
+    }
+}
+
+/// Get the position of a book in the canonical order
+/// `book` is not case-sensitive, and `psalm` is treated the same as `psalms`
+/// (some translations use the singular form)
+/// With the `apocrypha` feature enabled, `wisdom of solomon` and `ecclesiasticus` are
+/// treated the same as `wisdom` and `sirach`
+/// Returns `None` if the book is not part of the canon (i.e. a custom book)
+/// # Example
+/// ```
+/// use bible_lib::books::{canonical_index, CANONICAL_BOOKS};
+///
+/// // Genesis is the first book
+/// assert_eq!(canonical_index("Genesis"), Some(0));
+/// // Revelation is the last book
+/// assert_eq!(canonical_index("revelation"), Some(CANONICAL_BOOKS.len() - 1));
+/// ```
+pub fn canonical_index(book: &str) -> Option<usize> {
+    let book = book.to_lowercase();
+    let book = match book.as_str() {
+        "psalm" => "psalms",
+        #[cfg(feature = "apocrypha")]
+        "wisdom of solomon" => "wisdom",
+        #[cfg(feature = "apocrypha")]
+        "ecclesiasticus" => "sirach",
+        book => book,
+    };
+    CANONICAL_BOOKS.iter().position(|b| *b == book)
+}
+
+/// Common abbreviations for each canonical book
+/// The first abbreviation of each book is used as its short display form
+pub const BOOK_ABBREVIATIONS: &[(&str, &[&str])] = &[
+    // Old Testament
+    ("genesis", &["gen", "ge", "gn"]),
+    ("exodus", &["exod", "exo", "ex"]),
+    ("leviticus", &["lev", "le", "lv"]),
+    ("numbers", &["num", "nu", "nm", "nb"]),
+    ("deuteronomy", &["deut", "de", "dt"]),
+    ("joshua", &["josh", "jos", "jsh"]),
+    ("judges", &["judg", "jdg", "jg", "jdgs"]),
+    ("ruth", &["ruth", "rth", "ru"]),
+    ("1 samuel", &["1 sam", "1 sm", "1 sa", "1sam"]),
+    ("2 samuel", &["2 sam", "2 sm", "2 sa", "2sam"]),
+    ("1 kings", &["1 kgs", "1 ki", "1kgs"]),
+    ("2 kings", &["2 kgs", "2 ki", "2kgs"]),
+    ("1 chronicles", &["1 chr", "1 chron", "1 ch", "1chr"]),
+    ("2 chronicles", &["2 chr", "2 chron", "2 ch", "2chr"]),
+    ("ezra", &["ezra", "ezr", "ez"]),
+    ("nehemiah", &["neh", "ne"]),
+    #[cfg(feature = "apocrypha")]
+    ("tobit", &["tob", "tb"]),
+    #[cfg(feature = "apocrypha")]
+    ("judith", &["jdt", "jth"]),
+    ("esther", &["esth", "est", "es"]),
+    #[cfg(feature = "apocrypha")]
+    ("1 maccabees", &["1 macc", "1 mac", "1 mc", "1macc"]),
+    #[cfg(feature = "apocrypha")]
+    ("2 maccabees", &["2 macc", "2 mac", "2 mc", "2macc"]),
+    ("job", &["job", "jb"]),
+    ("psalms", &["ps", "psa", "psalm", "pss", "psm"]),
+    ("proverbs", &["prov", "pro", "prv", "pr"]),
+    ("ecclesiastes", &["eccl", "eccles", "ecc", "qoh"]),
+    ("song of solomon", &["song", "sos", "so", "song of songs"]),
+    #[cfg(feature = "apocrypha")]
+    ("wisdom", &["wis", "wisd", "ws", "wisdom of solomon"]),
+    #[cfg(feature = "apocrypha")]
+    ("sirach", &["sir", "ecclus", "ecclesiasticus"]),
+    ("isaiah", &["isa", "is"]),
+    ("jeremiah", &["jer", "je", "jr"]),
+    ("lamentations", &["lam", "la"]),
+    #[cfg(feature = "apocrypha")]
+    ("baruch", &["bar", "ba"]),
+    ("ezekiel", &["ezek", "eze", "ezk"]),
+    ("daniel", &["dan", "da", "dn"]),
+    ("hosea", &["hos", "ho"]),
+    ("joel", &["joel", "jl"]),
+    ("amos", &["amos", "am"]),
+    ("obadiah", &["obad", "ob"]),
+    ("jonah", &["jonah", "jnh", "jon"]),
+    ("micah", &["mic", "mc"]),
+    ("nahum", &["nah", "na"]),
+    ("habakkuk", &["hab", "hb"]),
+    ("zephaniah", &["zeph", "zep", "zp"]),
+    ("haggai", &["hag", "hg"]),
+    ("zechariah", &["zech", "zec", "zc"]),
+    ("malachi", &["mal", "ml"]),
+    // New Testament
+    ("matthew", &["matt", "mt"]),
+    ("mark", &["mark", "mrk", "mk", "mr"]),
+    ("luke", &["luke", "luk", "lk"]),
+    ("john", &["jn", "jhn", "joh"]),
+    ("acts", &["acts", "ac"]),
+    ("romans", &["rom", "ro", "rm"]),
+    ("1 corinthians", &["1 cor", "1 co", "1cor"]),
+    ("2 corinthians", &["2 cor", "2 co", "2cor"]),
+    ("galatians", &["gal", "ga"]),
+    ("ephesians", &["eph", "ephes"]),
+    ("philippians", &["phil", "php", "pp"]),
+    ("colossians", &["col", "co"]),
+    ("1 thessalonians", &["1 thess", "1 th", "1thess"]),
+    ("2 thessalonians", &["2 thess", "2 th", "2thess"]),
+    ("1 timothy", &["1 tim", "1 ti", "1tim"]),
+    ("2 timothy", &["2 tim", "2 ti", "2tim"]),
+    ("titus", &["titus", "tit", "ti"]),
+    ("philemon", &["phlm", "philem", "phm"]),
+    ("hebrews", &["heb"]),
+    ("james", &["jas", "jm"]),
+    ("1 peter", &["1 pet", "1 pe", "1 pt", "1pet"]),
+    ("2 peter", &["2 pet", "2 pe", "2 pt", "2pet"]),
+    ("1 john", &["1 jn", "1 jhn", "1jn"]),
+    ("2 john", &["2 jn", "2 jhn", "2jn"]),
+    ("3 john", &["3 jn", "3 jhn", "3jn"]),
+    ("jude", &["jude", "jud", "jd"]),
+    ("revelation", &["rev", "re", "rv"]),
+];
+
+/// Get the known abbreviations of a book
+/// `book` is not case-sensitive, and `psalm` is treated the same as `psalms`
+/// Returns an empty slice if the book is not part of the canon (i.e. a custom book)
+/// # Example
+/// ```
+/// use bible_lib::books::abbreviations;
+///
+/// // get the abbreviations for Genesis
+/// let abbreviations = abbreviations("Genesis");
+/// assert!(abbreviations.contains(&"gen"));
+/// ```
+pub fn abbreviations(book: &str) -> &'static [&'static str] {
+    let Some(index) = canonical_index(book) else {
+        return &[];
+    };
+    BOOK_ABBREVIATIONS.iter()
+        .find(|(name, _)| *name == CANONICAL_BOOKS[index])
+        .map(|(_, abbreviations)| *abbreviations)
+        .unwrap_or(&[])
+}
+
+/// Books that only have a single chapter
+/// References to these books can omit the chapter (i.e. `Jude 4` is `Jude 1:4`)
+pub const SINGLE_CHAPTER_BOOKS: &[&str] = &["obadiah", "philemon", "2 john", "3 john", "jude"];
+
+/// Check if a book only has a single chapter
+/// `book` is not case-sensitive
+/// # Example
+/// ```
+/// use bible_lib::books::is_single_chapter;
+///
+/// assert!(is_single_chapter("Jude"));
+/// assert!(!is_single_chapter("John"));
+/// ```
+pub fn is_single_chapter(book: &str) -> bool {
+    SINGLE_CHAPTER_BOOKS.contains(&book.to_lowercase().as_str())
+}
@@ -0,0 +1,47 @@
+use std::{collections::HashMap, sync::Mutex};
+
+/// The most chapters kept in a `ChapterCache` at once
+const CAPACITY: usize = 32;
+
+/// Memoized output of `Bible::get_chapter`, keyed by (book, chapter, use_superscripts)
+/// Uses a `Mutex` so `Bible` can stay `Sync` while caching from `&self` methods
+/// Holds at most `CAPACITY` chapters, starting over once full
+#[derive(Debug, Default)]
+pub(crate) struct ChapterCache {
+    chapters: Mutex<HashMap<(String, u32, bool), String>>,
+}
+
+impl ChapterCache {
+    /// Get a cached chapter
+    pub(crate) fn get(&self, book: &str, chapter: u32, use_superscripts: bool) -> Option<String> {
+        let chapters = self.chapters.lock().ok()?;
+        chapters.get(&(book.to_string(), chapter, use_superscripts)).cloned()
+    }
+
+    /// Cache a chapter
+    pub(crate) fn insert(&self, book: &str, chapter: u32, use_superscripts: bool, text: &str) {
+        if let Ok(mut chapters) = self.chapters.lock() {
+            // a reader only re-displays a handful of chapters, so starting over is cheaper than tracking use
+            if chapters.len() >= CAPACITY {
+                chapters.clear();
+            }
+            chapters.insert((book.to_string(), chapter, use_superscripts), text.to_string());
+        }
+    }
+
+    /// Remove every cached chapter
+    pub(crate) fn clear(&self) {
+        if let Ok(mut chapters) = self.chapters.lock() {
+            chapters.clear();
+        }
+    }
+}
+
+impl Clone for ChapterCache {
+    fn clone(&self) -> Self {
+        let chapters = self.chapters.lock().map(|chapters| chapters.clone()).unwrap_or_default();
+        Self {
+            chapters: Mutex::new(chapters),
+        }
+    }
+}
@@ -2,11 +2,21 @@ use std::fmt::{Display, Formatter};
 
 
 /// Errors that can occur in the Bible Lib
+#[derive(Debug)]
 pub enum BibleLibError {
     /// The specified custom translation file is invalid or does not exist.
     InvalidCustomTranslationFile,
     /// The specified verse was not found in the translation.
     VerseNotFound,
+    /// A verse within a requested range was not found in the translation.
+    /// Contains the number of the first missing verse.
+    VerseNotFoundAt(u32),
+    /// A requested range runs past the end of its chapter.
+    /// Contains the last verse number of the chapter.
+    RangeExceedsChapter(u32),
+    /// A requested range covers more verses than the Bible allows (see `Bible::with_max_range`).
+    /// Contains the maximum number of verses allowed.
+    RangeTooLong(usize),
     /// The specified chapter was not found in the translation.
     ChapterNotFound,
     /// The specified book was not found in the translation.
@@ -15,6 +25,9 @@ pub enum BibleLibError {
     InvalidVerseFormat,
     /// An I/O error occurred.
     IOError(std::io::Error),
+    /// A compressed translation file could not be decompressed.
+    #[cfg(feature = "gzip")]
+    DecompressionError(std::io::Error),
 }
 
 impl Display for BibleLibError {
@@ -26,6 +39,15 @@ impl Display for BibleLibError {
             BibleLibError::VerseNotFound => {
                 write!(f, "The specified verse was not found in the translation.")
             }
+            BibleLibError::VerseNotFoundAt(verse) => {
+                write!(f, "Verse {} was not found in the translation.", verse)
+            }
+            BibleLibError::RangeExceedsChapter(max_verse) => {
+                write!(f, "The requested range exceeds the chapter (max {}).", max_verse)
+            }
+            BibleLibError::RangeTooLong(max) => {
+                write!(f, "The requested range is too long (max {} verses).", max)
+            }
             BibleLibError::ChapterNotFound => {
                 write!(f, "The specified chapter was not found in the translation.")
             }
@@ -38,6 +60,10 @@ impl Display for BibleLibError {
             BibleLibError::IOError(e) => {
                 write!(f, "An I/O error occurred: {}", e)
             }
+            #[cfg(feature = "gzip")]
+            BibleLibError::DecompressionError(e) => {
+                write!(f, "The translation file could not be decompressed: {}", e)
+            }
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,50 @@
+use std::{collections::VecDeque, sync::Mutex};
+
+use crate::BibleLookup;
+
+/// Bounded list of the most recently accessed verses
+/// Uses a `Mutex` so `Bible` can record history from `&self` methods
+/// A capacity of 0 disables recording
+#[derive(Debug, Default)]
+pub(crate) struct History {
+    capacity: usize,
+    lookups: Mutex<VecDeque<BibleLookup>>,
+}
+
+impl History {
+    /// Create an empty history holding at most `capacity` lookups
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            lookups: Mutex::new(VecDeque::with_capacity(capacity)),
+        }
+    }
+
+    /// Record a lookup, dropping the oldest one if the history is full
+    pub(crate) fn push(&self, lookup: &BibleLookup) {
+        if self.capacity == 0 {
+            return;
+        }
+        if let Ok(mut lookups) = self.lookups.lock() {
+            if lookups.len() == self.capacity {
+                lookups.pop_front();
+            }
+            lookups.push_back(lookup.clone());
+        }
+    }
+
+    /// Get the recorded lookups, oldest first
+    pub(crate) fn lookups(&self) -> Vec<BibleLookup> {
+        self.lookups.lock().map(|lookups| lookups.iter().cloned().collect()).unwrap_or_default()
+    }
+}
+
+impl Clone for History {
+    fn clone(&self) -> Self {
+        let lookups = self.lookups.lock().map(|lookups| lookups.clone()).unwrap_or_default();
+        Self {
+            capacity: self.capacity,
+            lookups: Mutex::new(lookups),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use std::sync::OnceLock;
+
+use crate::{Bible, Translation, error::BibleLibError};
+
+/// A Bible that is only loaded and parsed the first time it is queried
+/// Useful when constructing many (custom) Bibles of which only a few are used,
+/// as `Bible::new` reads and parses the whole translation up front
+/// # Example
+/// ```
+/// use bible_lib::{BibleLookup, Translation, lazy::LazyBible};
+///
+/// // nothing is loaded yet
+/// let lazy = LazyBible::new(Translation::default());
+///
+/// // the translation is loaded on first access
+/// let bible = lazy.get().unwrap();
+/// let verse = bible.get_verse(BibleLookup::new("John", 3, 16), false).unwrap();
+///
+/// // print the verse text
+/// println!("John 3:16: {}", verse);
+/// ```
+#[derive(Debug)]
+pub struct LazyBible {
+    translation: Translation,
+    bible: OnceLock<Bible>,
+}
+
+impl LazyBible {
+    /// Create a new LazyBible without loading the translation
+    pub fn new(translation: Translation) -> Self {
+        Self {
+            translation,
+            bible: OnceLock::new(),
+        }
+    }
+
+    /// Get the translation of the LazyBible
+    pub fn get_translation(&self) -> &Translation {
+        &self.translation
+    }
+
+    /// Check if the translation has been loaded yet
+    pub fn is_loaded(&self) -> bool {
+        self.bible.get().is_some()
+    }
+
+    /// Get the Bible, loading the translation if this is the first access
+    /// Returns an error if loading fails, in which case the next call will try again
+    pub fn get(&self) -> Result<&Bible, BibleLibError> {
+        if let Some(bible) = self.bible.get() {
+            return Ok(bible);
+        }
+        let bible = Bible::new(self.translation.clone())?;
+        Ok(self.bible.get_or_init(|| bible))
+    }
+}
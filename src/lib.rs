@@ -13,11 +13,21 @@
        .'                   '. Art by Joan Stark
 */
 
-use std::{collections::HashMap, fmt::Display};
+use std::{collections::{HashMap, HashSet}, fmt::Display, str::FromStr, sync::OnceLock};
 
-use crate::error::BibleLibError;
+use crate::{cache::ChapterCache, error::BibleLibError, history::History, mapping::VerseMapping, parser::ParserConfig, passage::Passage, print::PrintStyle, search::{SearchOptions, WordIndex}};
 
+pub mod books;
+mod cache;
 pub mod error;
+mod history;
+pub mod lazy;
+pub mod mapping;
+pub mod parser;
+pub mod popularity;
+pub mod passage;
+pub mod print;
+pub mod search;
 
 #[cfg(feature = "akjv")]
 const AKJV: &str = include_str!("bible_translations/akjv.txt");
@@ -28,6 +38,27 @@ const ERV: &str = include_str!("bible_translations/erv.txt");
 #[cfg(feature = "kjv")]
 const KJV: &str = include_str!("bible_translations/kjv.txt");
 
+/// Book names recognized when detecting verses in a string
+#[cfg(feature = "detection")]
+const DETECTION_BOOKS: &str = r"genesis|exodus|leviticus|numbers|deuteronomy|joshua|judges|ruth|1\s?samuel|2\s?samuel|1\s?kings|2\s?kings|1\s?chronicles|2\s?chronicles|ezra|nehemiah|esther|job|psalms|proverbs|ecclesiastes|song\sof\ssolomon|isaiah|jeremiah|lamentations|ezekiel|daniel|hosea|joel|amos|obadiah|jonah|micah|nahum|habakkuk|zephaniah|haggai|zechariah|malachi|matthew|mark|luke|john|acts|romans|1\s?corinthians|2\s?corinthians|galatians|ephesians|philippians|colossians|1\s?thessalonians|2\s?thessalonians|1\s?timothy|2\s?timothy|titus|philemon|hebrews|james|1\s?peter|2\s?peter|1\s?john|2\s?john|3\s?john|jude|revelation";
+
+/// The regex matching verse references (i.e. `John 3:16` or `John 3:16-18`), compiled on first use
+#[cfg(feature = "detection")]
+#[doc(hidden)]
+fn verse_regex() -> &'static regex::Regex {
+    static REGEX: OnceLock<regex::Regex> = OnceLock::new();
+    // match case-insensitively instead of lowercasing the text so the byte ranges stay valid
+    REGEX.get_or_init(|| regex::Regex::new(&format!(r"(?i)\b(?:{})\s+\d+:\d+(?:-\d+)?\b", DETECTION_BOOKS)).unwrap())
+}
+
+/// The regex matching chapter ranges (i.e. `Genesis 1-2`), compiled on first use
+#[cfg(feature = "detection")]
+#[doc(hidden)]
+fn chapter_range_regex() -> &'static regex::Regex {
+    static REGEX: OnceLock<regex::Regex> = OnceLock::new();
+    REGEX.get_or_init(|| regex::Regex::new(&format!(r"(?i)\b(?:{})\s+\d+-\d+\b", DETECTION_BOOKS)).unwrap())
+}
+
 /// Different Bible Translations
 /// provided by https://openbible.com/
 /// https://openbible.com/texts.htm
@@ -53,23 +84,32 @@ pub enum Translation {
     ///
     /// note: other translations are included in the binary at compile time,
     /// but custom translations are read from the filesystem at runtime
-    Custom { name: String, path: String }
+    Custom { name: String, path: String },
+    /// A gzip-compressed custom translation, formatted like `Custom` once decompressed
+    /// Requires the `gzip` feature to be enabled
+    ///
+    /// `name` is strictly for display purposes
+    #[cfg(feature = "gzip")]
+    CustomGzip { name: String, path: String },
 }
 
 impl Translation {
     #[doc(hidden)]
     fn get_text(&self) -> Result<String, BibleLibError> {
         match self {
-
+            #[cfg(feature = "akjv")]
             Self::AmericanKingJames => {
                 Ok(AKJV.to_string())
             }
+            #[cfg(feature = "asv")]
             Self::AmericanStandard => {
                 Ok(ASV.to_string())
             }
+            #[cfg(feature = "erv")]
             Self::EnglishedRevised => {
                 Ok(ERV.to_string())
             }
+            #[cfg(feature = "kjv")]
             Self::KingJames => {
                 Ok(KJV.to_string())
             }
@@ -86,7 +126,89 @@ impl Translation {
                     Err(e) => Err(BibleLibError::IOError(e))
                 }
             }
+            #[cfg(feature = "gzip")]
+            Self::CustomGzip { path, .. } => {
+                use std::io::Read;
+
+                let file = std::fs::File::open(path).map_err(BibleLibError::IOError)?;
+                let mut text = String::new();
+                flate2::read::GzDecoder::new(file)
+                    .read_to_string(&mut text)
+                    .map_err(BibleLibError::DecompressionError)?;
+                Ok(text)
+            }
+        }
+    }
+
+    /// Get every built-in translation compiled into the binary along with
+    /// the size (in bytes) of its embedded text
+    /// Useful for diagnosing how much of the binary size comes from the enabled translations
+    /// # Example
+    /// ```
+    /// use bible_lib::Translation;
+    ///
+    /// // print each compiled translation and its size
+    /// for (translation, size) in Translation::compiled_translations() {
+    ///     println!("{}: {} bytes", translation, size);
+    /// }
+    /// ```
+    pub fn compiled_translations() -> Vec<(Translation, usize)> {
+        vec![
+            #[cfg(feature = "akjv")]
+            (Self::AmericanKingJames, AKJV.len()),
+            #[cfg(feature = "asv")]
+            (Self::AmericanStandard, ASV.len()),
+            #[cfg(feature = "erv")]
+            (Self::EnglishedRevised, ERV.len()),
+            #[cfg(feature = "kjv")]
+            (Self::KingJames, KJV.len()),
+        ]
+    }
+
+    /// Guess which compiled translation a piece of verse text most likely came from
+    /// Each translation is scored by the word overlap (from 0.0 to 1.0) between `text`
+    /// and its best matching verse, and the results are ranked from most to least likely
+    /// The compiled translations are parsed on the first call and kept in memory for the rest of the program,
+    /// so later calls only pay for the scoring (at the cost of holding every compiled translation in memory)
+    /// # Example
+    /// ```
+    /// use bible_lib::Translation;
+    ///
+    /// // identify the translation of a quote
+    /// let matches = Translation::identify_translation("Jesus wept.");
+    /// // print each translation and its score
+    /// for (translation, score) in matches {
+    ///     println!("{}: {:.2}", translation, score);
+    /// }
+    /// ```
+    pub fn identify_translation(text: &str) -> Vec<(Translation, f64)> {
+        let words = search::tokenize(text).into_iter().collect::<HashSet<String>>();
+        if words.is_empty() {
+            return Vec::new();
         }
+        let mut scores = Self::compiled_bibles().iter()
+            .map(|bible| {
+                let best = bible.verses.values()
+                    .flat_map(|chapters| chapters.values())
+                    .flat_map(|verses| verses.values())
+                    .map(|verse| search::word_similarity(&words, verse))
+                    .fold(0.0, f64::max);
+                (bible.translation.clone(), best)
+            })
+            .collect::<Vec<(Translation, f64)>>();
+        scores.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+        scores
+    }
+
+    /// Parse every compiled translation, only on the first call
+    #[doc(hidden)]
+    fn compiled_bibles() -> &'static [Bible] {
+        static BIBLES: OnceLock<Vec<Bible>> = OnceLock::new();
+        BIBLES.get_or_init(|| {
+            Self::compiled_translations().into_iter()
+                .filter_map(|(translation, _)| Bible::new(translation).ok())
+                .collect()
+        })
     }
 }
 
@@ -122,6 +244,8 @@ impl Display for Translation {
             #[cfg(feature = "kjv")]
             Self::KingJames => write!(f, "King James Version"),
             Self::Custom { name, .. } => write!(f, "Custom Translation: {}", name),
+            #[cfg(feature = "gzip")]
+            Self::CustomGzip { name, .. } => write!(f, "Custom Translation: {}", name),
         }
     }
 }
@@ -129,6 +253,8 @@ impl Display for Translation {
 /// Struct representing a Bible verse lookup
 /// `book` is not case-sensitive
 /// `thru_verse` is optional and used for verse ranges like `John 3:16-18`
+/// `thru_chapter` is optional and used for ranges spanning chapters like `John 3:35-4:2`
+/// (`thru_verse` is then the verse within `thru_chapter`)
 /// # Example
 /// ```
 /// use bible_lib::{Bible, BibleLookup, Translation};
@@ -143,35 +269,46 @@ impl Display for Translation {
 /// // print the verse text
 /// println!("John 3:16: {}", verse);
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct BibleLookup {
     pub book: String,
     pub chapter: u32,
     pub verse: u32,
     pub thru_verse: Option<u32>,
+    pub thru_chapter: Option<u32>,
 }
 
 impl BibleLookup {
     /// Create a new BibleLookup instance (single verse)
-    /// `book` is not case-sensitive
+    /// `book` is not case-sensitive, and extra whitespace in it is ignored (i.e. ` 1  samuel `)
     /// # Example
     /// ```
     /// use bible_lib::BibleLookup;
     ///
     /// // create a lookup for John 3:16
     /// let lookup = BibleLookup::new("John", 3, 16);
+    ///
+    /// // sloppy spacing is normalized
+    /// assert_eq!(BibleLookup::new(" 1  Samuel ", 3, 10).book, "1 samuel");
     /// ```
     pub fn new<S: Into<String>>(book: S, chapter: u32, verse: u32) -> Self {
-        let book = book.into();
-        let book = book.to_lowercase();
+        let book = Self::normalize_book(&book.into());
         Self {
             book,
             chapter,
             verse,
             thru_verse: None,
+            thru_chapter: None,
         }
     }
 
+    /// Normalize a book name to the form used as a key in `Bible::verses`:
+    /// lowercase, with surrounding whitespace removed and inner whitespace collapsed to single spaces
+    #[doc(hidden)]
+    fn normalize_book(book: &str) -> String {
+        book.split_whitespace().collect::<Vec<&str>>().join(" ").to_lowercase()
+    }
+
     /// Create a new BibleLookup instance (verse range)
     /// # Example
     /// ```
@@ -181,13 +318,184 @@ impl BibleLookup {
     /// let lookup = BibleLookup::new_range("Luke", 23, 39, 43);
     /// ```
     pub fn new_range<S: Into<String>>(book: S, chapter: u32, verse: u32, thru_verse: u32) -> Self {
-        let book = book.into();
-        let book = book.to_lowercase();
+        let book = Self::normalize_book(&book.into());
+        Self {
+            book,
+            chapter,
+            verse,
+            thru_verse: Some(thru_verse),
+            thru_chapter: None,
+        }
+    }
+
+    /// Create a new BibleLookup instance (verse range spanning chapters)
+    /// If `thru_chapter` is the same as `chapter`, this is the same as `new_range`
+    /// # Example
+    /// ```
+    /// use bible_lib::BibleLookup;
+    ///
+    /// // create a lookup for John 3:35-4:2
+    /// let lookup = BibleLookup::new_cross_range("John", 3, 35, 4, 2);
+    ///
+    /// // print the lookup
+    /// println!("{}", lookup); // John 3:35-4:2
+    /// ```
+    pub fn new_cross_range<S: Into<String>>(book: S, chapter: u32, verse: u32, thru_chapter: u32, thru_verse: u32) -> Self {
+        if thru_chapter == chapter {
+            return Self::new_range(book, chapter, verse, thru_verse);
+        }
+        let book = Self::normalize_book(&book.into());
         Self {
             book,
             chapter,
             verse,
             thru_verse: Some(thru_verse),
+            thru_chapter: Some(thru_chapter),
+        }
+    }
+
+    /// Create a new BibleLookup instance (single verse), checking that it is valid
+    /// Returns `InvalidVerseFormat` if the chapter or verse is 0, as chapters and verses start at 1
+    /// # Example
+    /// ```
+    /// use bible_lib::BibleLookup;
+    ///
+    /// assert!(BibleLookup::try_new("John", 3, 16).is_ok());
+    /// assert!(BibleLookup::try_new("John", 3, 0).is_err());
+    /// ```
+    pub fn try_new<S: Into<String>>(book: S, chapter: u32, verse: u32) -> Result<Self, BibleLibError> {
+        let lookup = Self::new(book, chapter, verse);
+        lookup.validate()?;
+        Ok(lookup)
+    }
+
+    /// Create a new BibleLookup instance (verse range), checking that it is valid
+    /// Returns `InvalidVerseFormat` if the chapter or either verse is 0, as chapters and verses start at 1
+    pub fn try_new_range<S: Into<String>>(book: S, chapter: u32, verse: u32, thru_verse: u32) -> Result<Self, BibleLibError> {
+        let lookup = Self::new_range(book, chapter, verse, thru_verse);
+        lookup.validate()?;
+        Ok(lookup)
+    }
+
+    /// Check that every chapter and verse number of the lookup is at least 1,
+    /// and that ranges don't end before they start
+    /// Returns `InvalidVerseFormat` otherwise
+    pub fn validate(&self) -> Result<(), BibleLibError> {
+        let numbers = [Some(self.chapter), Some(self.verse), self.thru_chapter, self.thru_verse];
+        if numbers.iter().flatten().any(|number| *number == 0) {
+            return Err(BibleLibError::InvalidVerseFormat);
+        }
+        let (start, end) = self.bounds();
+        if end < start {
+            return Err(BibleLibError::InvalidVerseFormat);
+        }
+        Ok(())
+    }
+
+    /// Parse a reference without validating the chapter and verse numbers
+    #[doc(hidden)]
+    fn parse_reference(s: &str) -> Result<Self, BibleLibError> {
+        // references lifted out of prose often carry trailing punctuation (i.e. `John 3:16,`)
+        let s = s.trim().trim_end_matches(['.', ',', ';', ')']).trim_end();
+        // the book name can contain whitespace (i.e. `1 samuel`), so split at the last whitespace
+        let Some((book, reference)) = s.rsplit_once(char::is_whitespace) else {
+            return Err(BibleLibError::InvalidVerseFormat);
+        };
+        let book = book.trim();
+        if book.is_empty() {
+            return Err(BibleLibError::InvalidVerseFormat);
+        }
+
+        let parse_number = |n: &str| n.trim().parse::<u32>().map_err(|_| BibleLibError::InvalidVerseFormat);
+
+        // ranges may be written with a hyphen or a typographic dash (i.e. `John 3:16–18`)
+        let (start, end) = match reference.split_once(['-', '–', '—']) {
+            Some((start, end)) => (start, Some(end)),
+            None => (reference, None),
+        };
+        let Some((chapter, verse)) = start.split_once(':') else {
+            // single chapter books can omit the chapter (i.e. `Jude 4` or `Jude 4-7`)
+            if !books::is_single_chapter(book) {
+                return Err(BibleLibError::InvalidVerseFormat);
+            }
+            let verse = parse_number(start)?;
+            return match end {
+                Some(end) => Ok(Self::new_range(book, 1, verse, parse_number(end)?)),
+                None => Ok(Self::new(book, 1, verse)),
+            };
+        };
+        let chapter = parse_number(chapter)?;
+        let verse = parse_number(verse)?;
+
+        match end {
+            // cross-chapter range (i.e. `3:35-4:2`)
+            Some(end) if end.contains(':') => {
+                let (thru_chapter, thru_verse) = end.split_once(':').unwrap();
+                Ok(Self::new_cross_range(book, chapter, verse, parse_number(thru_chapter)?, parse_number(thru_verse)?))
+            }
+            // single chapter range (i.e. `3:16-18`)
+            Some(end) => Ok(Self::new_range(book, chapter, verse, parse_number(end)?)),
+            // single verse
+            None => Ok(Self::new(book, chapter, verse)),
+        }
+    }
+
+    /// Parse a BibleLookup from a path-like segment, such as from a URL
+    /// Accepts `book/chapter/verse` and `book.chapter.verse`, where multi-word books
+    /// are joined with `-` (i.e. `1-corinthians/13/4`) and ranges use a trailing `-` (i.e. `john/3/16-18`)
+    /// `book` is not case-sensitive
+    /// # Example
+    /// ```
+    /// use bible_lib::BibleLookup;
+    ///
+    /// // parse a lookup for 1 Corinthians 13:4-7
+    /// let lookup = BibleLookup::from_path_segment("1-corinthians/13/4-7").unwrap();
+    ///
+    /// assert_eq!(lookup, BibleLookup::new_range("1 Corinthians", 13, 4, 7));
+    /// ```
+    pub fn from_path_segment(segment: &str) -> Result<Self, BibleLibError> {
+        let segment = segment.trim().trim_matches('/');
+        let parts = segment.split(['/', '.']).collect::<Vec<&str>>();
+        let [book, chapter, verse] = parts.as_slice() else {
+            return Err(BibleLibError::InvalidVerseFormat);
+        };
+
+        let book = book.replace('-', " ");
+        if book.trim().is_empty() {
+            return Err(BibleLibError::InvalidVerseFormat);
+        }
+        let parse_number = |n: &str| n.parse::<u32>().map_err(|_| BibleLibError::InvalidVerseFormat);
+        let chapter = parse_number(chapter)?;
+
+        let lookup = match verse.split_once('-') {
+            // range spanning chapters (i.e. `john/3/35-4:2`)
+            Some((verse, thru)) if thru.contains(':') => {
+                let (thru_chapter, thru_verse) = thru.split_once(':').unwrap();
+                Self::new_cross_range(book, chapter, parse_number(verse)?, parse_number(thru_chapter)?, parse_number(thru_verse)?)
+            }
+            Some((verse, thru_verse)) => Self::new_range(book, chapter, parse_number(verse)?, parse_number(thru_verse)?),
+            None => Self::new(book, chapter, parse_number(verse)?),
+        };
+        lookup.validate()?;
+        Ok(lookup)
+    }
+
+    /// Format the lookup as a path-like segment, such as for a URL (i.e. `john/3/16` or `1-corinthians/13/4-7`)
+    /// This is the inverse of `from_path_segment`
+    /// # Example
+    /// ```
+    /// use bible_lib::BibleLookup;
+    ///
+    /// let lookup = BibleLookup::new_range("1 Corinthians", 13, 4, 7);
+    ///
+    /// assert_eq!(lookup.to_path_segment(), "1-corinthians/13/4-7");
+    /// ```
+    pub fn to_path_segment(&self) -> String {
+        let book = self.book.split_whitespace().collect::<Vec<&str>>().join("-");
+        match (self.thru_chapter, self.thru_verse) {
+            (Some(thru_chapter), Some(thru_verse)) => format!("{}/{}/{}-{}:{}", book, self.chapter, self.verse, thru_chapter, thru_verse),
+            (None, Some(thru_verse)) => format!("{}/{}/{}-{}", book, self.chapter, self.verse, thru_verse),
+            _ => format!("{}/{}/{}", book, self.chapter, self.verse),
         }
     }
 
@@ -209,60 +517,306 @@ impl BibleLookup {
     /// // iterate through the found verses and print them
     /// for verse in verses {
     ///     // get the verse text
-    ///     let verse_text = bible.get_verse(verse.clone()).unwrap();
+    ///     let verse_text = bible.get_verse(verse.clone(), false).unwrap();
     ///     // print the verse text
     ///     println!("Found verse: {} - {}", verse, verse_text);
     /// }
     /// ```
     #[cfg(feature = "detection")]
     pub fn detect_from_string<S: Into<String>>(lookup: S) -> Vec<Self> {
-        let mut verses = Vec::new();
-
         let lookup = lookup.into();
-        let text = lookup.to_lowercase();
+        Self::detect_spans(&lookup).into_iter().map(|(verse, _)| verse).collect()
+    }
 
-        //let regex = regex::Regex::new(r"\b(?:genesis|exodus|leviticus|numbers|deuteronomy|joshua|judges|ruth|1\s?samuel|2\s?samuel|1\s?kings|2\s?kings|1\s?chronicles|2\s?chronicles|ezra|nehemiah|esther|job|psalms|proverbs|ecclesiastes|song\sof\ssolomon|isaiah|jeremiah|lamentations|ezekiel|daniel|hosea|joel|amos|obadiah|jonah|micah|nahum|habakkuk|zephaniah|haggai|zechariah|malachi|matthew|mark|luke|john|acts|romans|1\s?corinthians|2\s?corinthians|galatians|ephesians|philippians|colossians|1\s?thessalonians|2\s?thessalonians|1\s?timothy|2\s?timothy|titus|philemon|hebrews|james|1\s?peter|2\s?peter|1\s?john|2\s?john|3\s?john|jude|revelation)\s+\d+:\d+\b").unwrap();
-        let regex = regex::Regex::new(r"\b(?:genesis|exodus|leviticus|numbers|deuteronomy|joshua|judges|ruth|1\s?samuel|2\s?samuel|1\s?kings|2\s?kings|1\s?chronicles|2\s?chronicles|ezra|nehemiah|esther|job|psalms|proverbs|ecclesiastes|song\sof\ssolomon|isaiah|jeremiah|lamentations|ezekiel|daniel|hosea|joel|amos|obadiah|jonah|micah|nahum|habakkuk|zephaniah|haggai|zechariah|malachi|matthew|mark|luke|john|acts|romans|1\s?corinthians|2\s?corinthians|galatians|ephesians|philippians|colossians|1\s?thessalonians|2\s?thessalonians|1\s?timothy|2\s?timothy|titus|philemon|hebrews|james|1\s?peter|2\s?peter|1\s?john|2\s?john|3\s?john|jude|revelation)\s+\d+:\d+(?:-\d+)?\b").unwrap();
-        
-        for instance in regex.find_iter(&text) {
-            let instance = instance.as_str();
+    /// Detect Bible verses in a string, without duplicates
+    /// Requires the `detection` feature to be enabled
+    /// Verses are returned in the order they are first found in the string
+    /// Use `detect_from_string` to get every occurrence instead
+    /// # Example
+    /// ```
+    /// use bible_lib::BibleLookup;
+    ///
+    /// // detect verses in the string
+    /// let verses = BibleLookup::detect_unique("John 3:16 and Romans 8:28, but mostly John 3:16");
+    ///
+    /// assert_eq!(verses, vec![BibleLookup::new("John", 3, 16), BibleLookup::new("Romans", 8, 28)]);
+    /// ```
+    #[cfg(feature = "detection")]
+    pub fn detect_unique<S: Into<String>>(lookup: S) -> Vec<Self> {
+        let mut seen = HashSet::new();
+        Self::detect_from_string(lookup).into_iter()
+            .filter(|verse| seen.insert(verse.clone()))
+            .collect()
+    }
+
+    /// Detect Bible verses in a string along with the byte range each was found at
+    #[cfg(feature = "detection")]
+    #[doc(hidden)]
+    fn detect_spans(text: &str) -> Vec<(Self, std::ops::Range<usize>)> {
+        let mut verses = Vec::new();
+
+        for instance in verse_regex().find_iter(text) {
+            let span = instance.range();
+            let instance = instance.as_str().to_lowercase();
             // to handle cases like `1 samuel` and `Song of Solomon`, split by ':' first and then split by whitespace
             let mut parts = instance.split(':');
             // split the first part by whitespace
             let book_chapter = parts.next().unwrap().split_whitespace();
             let count = book_chapter.clone().count();
-            let chapter = book_chapter.clone().last().unwrap().parse::<u32>().unwrap();
-            let book = book_chapter.take(count - 1).collect::<Vec<&str>>().join(" ").to_lowercase();
+            let chapter = book_chapter.clone().next_back().unwrap().parse::<u32>().unwrap();
+            let book = book_chapter.take(count - 1).collect::<Vec<&str>>().join(" ");
 
             // handle cases where the verse is a range (i.e. `1-3`)
             let verse_part = parts.next().unwrap();
-            if verse_part.contains('-') {
-                let verse_split = verse_part.split('-');
-                let verse = verse_split.clone().next().unwrap().parse::<u32>().unwrap();
-                let thru_verse = verse_split.clone().last().unwrap().parse::<u32>().unwrap();
-                verses.push(BibleLookup {
+            if let Some((verse, thru_verse)) = verse_part.split_once('-') {
+                let verse = verse.parse::<u32>().unwrap();
+                let thru_verse = thru_verse.parse::<u32>().unwrap();
+                verses.push((BibleLookup {
                     book,
                     chapter,
                     verse,
                     thru_verse: Some(thru_verse),
-                });
+                    thru_chapter: None,
+                }, span));
             } else {
                 let verse = verse_part.parse::<u32>().unwrap();
-                verses.push(BibleLookup {
+                verses.push((BibleLookup {
                     book,
                     chapter,
                     verse,
                     thru_verse: None,
-                });
+                    thru_chapter: None,
+                }, span));
             }
         }
 
         verses
     }
 
+    /// Detect Bible verses in markdown, skipping inline code spans and fenced code blocks
+    /// Requires the `detection` feature to be enabled
+    /// Each verse is returned with the byte range it was found at in the original markdown
+    /// # Example
+    /// ```
+    /// use bible_lib::BibleLookup;
+    ///
+    /// let markdown = "See John 3:16, not `John 1:1`.\n```\nRomans 8:28\n```\n";
+    /// // detect verses outside of code
+    /// let verses = BibleLookup::detect_in_markdown(markdown);
+    ///
+    /// assert_eq!(verses.len(), 1);
+    /// assert_eq!(verses[0].0, BibleLookup::new("John", 3, 16));
+    /// assert_eq!(&markdown[verses[0].1.clone()], "John 3:16");
+    /// ```
+    #[cfg(feature = "detection")]
+    pub fn detect_in_markdown(s: &str) -> Vec<(Self, std::ops::Range<usize>)> {
+        let code = Self::markdown_code_ranges(s);
+        Self::detect_spans(s).into_iter()
+            .filter(|(_, span)| !code.iter().any(|range| range.start < span.end && span.start < range.end))
+            .collect()
+    }
+
+    /// Find the byte ranges of fenced code blocks and inline code spans in markdown
+    /// Inline code spans are only matched within a single line
+    #[cfg(feature = "detection")]
+    #[doc(hidden)]
+    fn markdown_code_ranges(text: &str) -> Vec<std::ops::Range<usize>> {
+        let mut ranges = Vec::new();
+        // the start of the open fence and its marker (i.e. "```" or "~~~~")
+        let mut fence: Option<(usize, String)> = None;
+        let mut offset = 0;
+
+        for line in text.split_inclusive('\n') {
+            let trimmed = line.trim_start();
+            if let Some((start, marker)) = &fence {
+                // a fence is closed by a run of at least as many of the same character
+                if trimmed.starts_with(marker.as_str()) {
+                    ranges.push(*start..offset + line.len());
+                    fence = None;
+                }
+            } else {
+                let first = trimmed.chars().next().filter(|c| *c == '`' || *c == '~');
+                let run = first.map_or(0, |first| trimmed.chars().take_while(|c| *c == first).count());
+                if let Some(first) = first && run >= 3 {
+                    fence = Some((offset, first.to_string().repeat(run)));
+                } else {
+                    // inline code spans open and close with backtick runs of the same length
+                    let bytes = line.as_bytes();
+                    let mut i = 0;
+                    while i < bytes.len() {
+                        if bytes[i] != b'`' {
+                            i += 1;
+                            continue;
+                        }
+                        let open = bytes[i..].iter().take_while(|b| **b == b'`').count();
+                        let mut j = i + open;
+                        let mut close = None;
+                        while j < bytes.len() {
+                            if bytes[j] == b'`' {
+                                let run = bytes[j..].iter().take_while(|b| **b == b'`').count();
+                                if run == open {
+                                    close = Some(j + run);
+                                    break;
+                                }
+                                j += run;
+                            } else {
+                                j += 1;
+                            }
+                        }
+                        match close {
+                            Some(end) => {
+                                ranges.push(offset + i..offset + end);
+                                i = end;
+                            }
+                            // an unmatched run is literal text
+                            None => i += open,
+                        }
+                    }
+                }
+            }
+            offset += line.len();
+        }
+
+        // an unclosed fence runs to the end of the document
+        if let Some((start, _)) = fence {
+            ranges.push(start..text.len());
+        }
+
+        ranges
+    }
+
+    /// Get the first and last (chapter, verse) covered by this lookup
+    #[doc(hidden)]
+    fn bounds(&self) -> ((u32, u32), (u32, u32)) {
+        let start = (self.chapter, self.verse);
+        let end = match (self.thru_chapter, self.thru_verse) {
+            (Some(thru_chapter), Some(thru_verse)) => (thru_chapter, thru_verse),
+            (None, Some(thru_verse)) => (self.chapter, thru_verse),
+            _ => start,
+        };
+        (start, end)
+    }
+
+    /// Check if this lookup and `other` are in the same book, comparing canonical books by their canonical name
+    /// (i.e. `Psalm` is the same book as `psalms`) and other books by their normalized name
+    #[doc(hidden)]
+    fn same_book(&self, other: &BibleLookup) -> bool {
+        let (book, other_book) = (Self::normalize_book(&self.book), Self::normalize_book(&other.book));
+        match (books::canonical_index(&book), books::canonical_index(&other_book)) {
+            (Some(index), Some(other_index)) => index == other_index,
+            _ => book == other_book,
+        }
+    }
+
+    /// Check if every verse of `other` is within this lookup
+    /// Handles single verses, verse ranges and ranges spanning chapters
+    /// # Example
+    /// ```
+    /// use bible_lib::BibleLookup;
+    ///
+    /// let range = BibleLookup::new_range("John", 3, 16, 20);
+    ///
+    /// assert!(range.contains_lookup(&BibleLookup::new("John", 3, 18)));
+    /// assert!(!range.contains_lookup(&BibleLookup::new_range("John", 3, 18, 22)));
+    ///
+    /// // books are compared by their canonical name
+    /// assert!(BibleLookup::new_range("Psalms", 23, 1, 6).contains_lookup(&BibleLookup::new("Psalm", 23, 3)));
+    /// ```
+    pub fn contains_lookup(&self, other: &BibleLookup) -> bool {
+        if !self.same_book(other) {
+            return false;
+        }
+        let (start, end) = self.bounds();
+        let (other_start, other_end) = other.bounds();
+        start <= other_start && other_end <= end
+    }
+
+    /// Check if this lookup and `other` share at least one verse
+    /// Handles single verses, verse ranges and ranges spanning chapters
+    /// # Example
+    /// ```
+    /// use bible_lib::BibleLookup;
+    ///
+    /// let range = BibleLookup::new_range("John", 3, 16, 20);
+    ///
+    /// assert!(range.overlaps(&BibleLookup::new_range("John", 3, 18, 22)));
+    /// assert!(!range.overlaps(&BibleLookup::new("John", 4, 1)));
+    ///
+    /// // lookups built by hand don't need a lowercase book
+    /// let verse = BibleLookup { book: "John".to_string(), chapter: 3, verse: 17, thru_verse: None, thru_chapter: None };
+    /// assert!(range.overlaps(&verse));
+    /// ```
+    pub fn overlaps(&self, other: &BibleLookup) -> bool {
+        if !self.same_book(other) {
+            return false;
+        }
+        let (start, end) = self.bounds();
+        let (other_start, other_end) = other.bounds();
+        start <= other_end && other_start <= end
+    }
+
+    /// Expand a verse range into a single verse lookup for each verse it covers (i.e. `John 3:16-18`
+    /// becomes `John 3:16`, `John 3:17` and `John 3:18`)
+    /// A single verse expands to itself
+    /// Note: this only works for ranges within a single chapter, as a lookup doesn't know where a chapter ends,
+    /// so ranges spanning chapters are returned unexpanded
+    /// # Example
+    /// ```
+    /// use bible_lib::BibleLookup;
+    ///
+    /// let verses = BibleLookup::new_range("John", 3, 16, 18).expand();
+    ///
+    /// assert_eq!(verses, vec![
+    ///     BibleLookup::new("John", 3, 16),
+    ///     BibleLookup::new("John", 3, 17),
+    ///     BibleLookup::new("John", 3, 18),
+    /// ]);
+    /// ```
+    pub fn expand(&self) -> Vec<BibleLookup> {
+        if self.thru_chapter.is_some_and(|thru_chapter| thru_chapter != self.chapter) {
+            return vec![self.clone()];
+        }
+        let thru_verse = self.thru_verse.unwrap_or(self.verse);
+        (self.verse..=thru_verse)
+            .map(|verse| BibleLookup::new(self.book.as_str(), self.chapter, verse))
+            .collect()
+    }
+
+    /// Format the chapter and verse part of the reference (i.e. `3:16`, `3:16-18` or `3:35-4:2`)
+    #[doc(hidden)]
+    fn chapter_verse(&self) -> String {
+        if let (Some(thru_chapter), Some(thru_verse)) = (self.thru_chapter, self.thru_verse) {
+            format!("{}:{}-{}:{}", self.chapter, self.verse, thru_chapter, thru_verse)
+        } else if let Some(thru_verse) = self.thru_verse {
+            format!("{}:{}-{}", self.chapter, self.verse, thru_verse)
+        } else {
+            format!("{}:{}", self.chapter, self.verse)
+        }
+    }
+
+    /// Format the reference using the book's abbreviation (i.e. `Jn 3:16` instead of `John 3:16`)
+    /// Falls back to the full book name if no abbreviation is known (i.e. custom books)
+    /// # Example
+    /// ```
+    /// use bible_lib::BibleLookup;
+    ///
+    /// let lookup = BibleLookup::new("John", 3, 16);
+    ///
+    /// assert_eq!(lookup.display_abbreviated(), "Jn 3:16");
+    /// ```
+    pub fn display_abbreviated(&self) -> String {
+        let book = match books::abbreviations(&self.book).first() {
+            Some(abbreviation) => Self::capitalize_book(abbreviation),
+            None => Self::capitalize_book(&self.book),
+        };
+        format!("{} {}", book, self.chapter_verse())
+    }
+
     /// Capitalize the first letter of each word in the book name
     /// Handles cases like `1 samuel` and `song of solomon`
     /// This is used because book names are stored in lowercase for easier lookup
+    /// Leading and trailing whitespace is trimmed, but the whitespace between words is kept
+    /// as-is, so custom book names with unusual spacing display predictably
     /// # Example
     /// ```
     /// use bible_lib::BibleLookup;
@@ -275,44 +829,93 @@ impl BibleLookup {
     /// println!("Capitalized Book 1: {}", book1); // John
     /// println!("Capitalized Book 2: {}", book2); // 1 Samuel
     /// 
+    /// // spacing between words is preserved
+    /// assert_eq!(BibleLookup::capitalize_book("song  of  solomon"), "Song  Of  Solomon");
     /// ```
-    pub fn capitalize_book(name: &String) -> String {
-        // capitalize the first letter of each word in the book name
-        // Split the input string by whitespace into words
-        name.split_whitespace()
-            // For each word, apply the following transformation
-            .map(|word| {
-                // Convert the word into characters
-                let mut chars = word.chars();
-                // If there's a first character, convert it to uppercase and concatenate it with the rest of the characters
-                if let Some(first_char) = chars.next() {
-                    if first_char.is_numeric() {
-                        // If the first character is numeric, leave it unchanged
-                        first_char.to_string() + &chars.collect::<String>()
-                    } else {
-                        // If the first character is not numeric, capitalize it
-                        first_char.to_uppercase().chain(chars).collect::<String>()
-                    }
-                } else {
-                    // If the word is empty, return an empty string
-                    String::new()
-                }
-            })
-            // Collect the transformed words back into a single string, separated by whitespace
-            .collect::<Vec<String>>().join(" ")
+    pub fn capitalize_book(name: &str) -> String {
+        // capitalize the first character of each word, leaving numbers
+        // and the whitespace between words unchanged
+        let mut capitalized = String::with_capacity(name.len());
+        let mut word_start = true;
+        for c in name.trim().chars() {
+            if word_start {
+                capitalized.extend(c.to_uppercase());
+            } else {
+                capitalized.push(c);
+            }
+            word_start = c.is_whitespace();
+        }
+        capitalized
     }
 }
 
 impl Display for BibleLookup {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if let Some(thru_verse) = self.thru_verse {
-            write!(f, "{} {}:{}-{}", Self::capitalize_book(&self.book), self.chapter, self.verse, thru_verse)
-        } else {
-            write!(f, "{} {}:{}", Self::capitalize_book(&self.book), self.chapter, self.verse)
+        write!(f, "{} {}", Self::capitalize_book(&self.book), self.chapter_verse())
+    }
+}
+
+/// Parse a BibleLookup from a string
+/// Accepts single verses (`John 3:16`), verse ranges (`John 3:16-18`)
+/// and ranges spanning chapters (`John 3:35-4:2`)
+/// Books with a single chapter can omit the chapter (`Jude 4` is `Jude 1:4`)
+/// `book` is not case-sensitive
+/// Trailing punctuation (`.`, `,`, `;` and `)`) is ignored, so references can be lifted out of sentences
+/// Spans written as two full references in the same book are accepted as well
+/// (`Matthew 5:1 to Matthew 7:29` or `Matthew 5:1–Matthew 7:29`); see `passage::Passage` for spans across books
+/// Returns `InvalidVerseFormat` if the reference is malformed or any chapter or verse is 0
+/// # Example
+/// ```
+/// use bible_lib::BibleLookup;
+///
+/// // parse a lookup for John 3:35-4:2
+/// let lookup: BibleLookup = "John 3:35-4:2".parse().unwrap();
+///
+/// assert_eq!(lookup, BibleLookup::new_cross_range("John", 3, 35, 4, 2));
+///
+/// // parse a lookup for Jude 1:4
+/// let lookup: BibleLookup = "Jude 4".parse().unwrap();
+/// assert_eq!(lookup, BibleLookup::new("Jude", 1, 4));
+///
+/// // trailing punctuation is ignored
+/// for reference in ["John 3:16,", "John 3:16.", "John 3:16;", "John 3:16)", "John 3:16)."] {
+///     let lookup: BibleLookup = reference.parse().unwrap();
+///     assert_eq!(lookup, BibleLookup::new("John", 3, 16));
+/// }
+/// let lookup: BibleLookup = "John 3:16-18.".parse().unwrap();
+/// assert_eq!(lookup, BibleLookup::new_range("John", 3, 16, 18));
+///
+/// // parse a span written as two references
+/// let lookup: BibleLookup = "Matthew 5:1 to Matthew 7:29".parse().unwrap();
+/// assert_eq!(lookup, BibleLookup::new_cross_range("Matthew", 5, 1, 7, 29));
+/// ```
+impl FromStr for BibleLookup {
+    type Err = BibleLibError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // two full references within the same book (i.e. `Matthew 5:1 to Matthew 7:29`)
+        if passage::split_passage(s).is_some() {
+            let lookup = s.parse::<Passage>()?.to_lookup().ok_or(BibleLibError::InvalidVerseFormat)?;
+            lookup.validate()?;
+            return Ok(lookup);
         }
+        let lookup = Self::parse_reference(s)?;
+        lookup.validate()?;
+        Ok(lookup)
     }
 }
 
+/// Book -> Chapter -> Verse -> Text
+type VerseMap = HashMap<String, HashMap<u32, HashMap<u32, String>>>;
+/// (Book, Chapter, Verse) of each verse that starts a new paragraph
+type ParagraphStarts = HashSet<(String, u32, u32)>;
+/// A book with its chapters, each with its verses, as returned by `Bible::to_ordered`
+/// (Book, [(Chapter, [(Verse, Text)])])
+pub type OrderedBook = (String, Vec<(u32, Vec<(u32, String)>)>);
+/// Verses of a single chapter, as returned by `Bible::search_grouped`
+/// (Book, Chapter, [(Verse, Text)])
+pub type ChapterGroup = (String, u32, Vec<(u32, String)>);
+
 /// Main Bible struct
 /// Stores the verses of the Bible for interfacing
 /// # Example
@@ -333,60 +936,396 @@ impl Display for BibleLookup {
 #[derive(Debug, Clone)]
 pub struct Bible {
     translation: Translation,
-    pub verses: HashMap<String /* Book */,
+    verses: HashMap<String /* Book */,
                 HashMap<u32 /* Chapter */,
                 HashMap<u32 /* Verse */, String /* Text */>>>,
+    paragraph_starts: ParagraphStarts,
+    chapter_cache: ChapterCache,
+    history: History,
+    max_range: usize,
 }
 
 impl Bible {
 
+    /// The default maximum number of verses a single `get_verse` range may cover
+    pub const DEFAULT_MAX_RANGE: usize = 500;
+
     #[doc(hidden)]
-    fn parse_text(lines: &String) -> HashMap<String, HashMap<u32, HashMap<u32, String>>> {
+    fn parse_text(lines: &str, config: &ParserConfig) -> Result<(VerseMap, ParagraphStarts), BibleLibError> {
         let mut verses = HashMap::new();
+        let mut paragraph_starts = HashSet::new();
+        let mut new_paragraph = false;
 
         for line in lines.lines() {
-            // to handle cases like `1 samuel` and `Song of Solomon`, split by ':' first and then split by whitespace
-            let mut parts = line.split(':');
-            // split the first part by whitespace
-            let book_chapter = parts.next().unwrap().split_whitespace();
-            let count = book_chapter.clone().count();
-            let chapter = book_chapter.clone().last().unwrap().parse::<u32>().unwrap();
-            let book = book_chapter.take(count - 1).collect::<Vec<&str>>().join(" ").to_lowercase();
-
-            let verse_text = parts.next().unwrap().split_whitespace();
-            let verse = verse_text.clone().next().unwrap().parse::<u32>().unwrap();
-            let text = verse_text.clone().skip(1).collect::<Vec<&str>>().join(" ");
-
-            if !verses.contains_key(&book) {
-                verses.insert(book.to_string(), HashMap::new());
+            // blank lines mark the start of a new paragraph
+            if line.trim().is_empty() {
+                new_paragraph = true;
+                continue;
             }
-            if !verses.get_mut(&book).unwrap().contains_key(&chapter) {
-                verses.get_mut(&book).unwrap().insert(chapter, HashMap::new());
+            let Some((book, chapter, verse, text)) = Self::parse_line(line, config) else {
+                return Err(BibleLibError::InvalidCustomTranslationFile);
+            };
+
+            // as do verses starting with a pilcrow
+            if new_paragraph || text.starts_with('¶') {
+                paragraph_starts.insert((book.clone(), chapter, verse));
+                new_paragraph = false;
             }
-            verses.get_mut(&book).unwrap().get_mut(&chapter).unwrap().insert(verse, text.to_string());
+
+            verses.entry(book)
+                .or_insert_with(HashMap::new)
+                .entry(chapter)
+                .or_insert_with(HashMap::new)
+                .insert(verse, text);
         }
 
-        verses
+        Ok((verses, paragraph_starts))
     }
 
-    /// Create a new Bible instance with the specified translation
-    pub fn new(translation: Translation) -> Result<Self, BibleLibError> {
-        let text = translation.get_text()?;
-        let verses = Self::parse_text(&text);
-        Ok(Self {
-            translation,
-            verses,
-        })
-    }
+    /// Parse a single line into its book, chapter, verse and text
+    #[doc(hidden)]
+    fn parse_line(line: &str, config: &ParserConfig) -> Option<(String, u32, u32, String)> {
+        // split the reference from the text
+        let (reference, text) = match &config.separator {
+            Some(separator) => line.split_once(separator.as_str())?,
+            None => {
+                // the reference ends at the first whitespace after the ':'
+                let colon = line.find(':')?;
+                match line[colon..].find(char::is_whitespace) {
+                    Some(end) => line.split_at(colon + end),
+                    None => (line, ""),
+                }
+            }
+        };
 
-    /// Get the current translation of the Bible instance
-    pub fn get_translation(&self) -> &Translation {
-        &self.translation
+        // to handle cases like `1 samuel` and `Song of Solomon`, split the book at the last whitespace
+        let (book, chapter_verse) = reference.trim().rsplit_once(char::is_whitespace)?;
+        let (chapter, verse) = chapter_verse.split_once(':')?;
+        let chapter = chapter.parse::<u32>().ok()?;
+        let verse = verse.parse::<u32>().ok()?;
+        let book = BibleLookup::normalize_book(book);
+        let text = text.split_whitespace().collect::<Vec<&str>>().join(" ");
+        let text = if config.normalize_quotes { parser::normalize_text(&text) } else { text };
+        let text = if config.strip_leading_reference { parser::strip_leading_reference(&book, chapter, verse, &text) } else { text };
+
+        Some((book, chapter, verse, text))
     }
 
-    #[doc(hidden)]
-    fn replace_superscript(s: String) -> String {
-        s.chars().map(|c| {
+    /// Create a new Bible instance from a gzip-compressed translation file
+    /// The decompressed file must be formatted like any other custom translation
+    /// `name` is strictly for display purposes
+    /// This is the same as `Bible::new` with `Translation::CustomGzip`, so the translation can be reloaded
+    /// Requires the `gzip` feature to be enabled
+    /// # Example
+    /// ```
+    /// use std::io::Write;
+    /// use bible_lib::{Bible, BibleLookup};
+    ///
+    /// // write a compressed translation
+    /// let path = std::env::temp_dir().join("bible_lib_from_gz_path.txt.gz");
+    /// let mut encoder = flate2::write::GzEncoder::new(std::fs::File::create(&path).unwrap(), flate2::Compression::default());
+    /// encoder.write_all(b"John 11:35 Jesus wept.").unwrap();
+    /// encoder.finish().unwrap();
+    ///
+    /// // load it, then reload it from its translation
+    /// let bible = Bible::from_gz_path("Compressed", path.to_string_lossy()).unwrap();
+    /// let reloaded = Bible::new(bible.get_translation().clone()).unwrap();
+    /// assert_eq!(reloaded.get_verse(BibleLookup::new("John", 11, 35), false).unwrap(), "Jesus wept.");
+    /// ```
+    #[cfg(feature = "gzip")]
+    pub fn from_gz_path<S: Into<String>, P: Into<String>>(name: S, path: P) -> Result<Self, BibleLibError> {
+        Self::new(Translation::CustomGzip { name: name.into(), path: path.into() })
+    }
+
+    /// Find verses that are defined more than once in the text of a translation
+    /// When parsing, later definitions silently replace earlier ones, so this helps catch copy-paste errors
+    /// Returns each duplicated verse with the (1-based) line numbers of its first definition and the duplicate
+    /// Lines that cannot be parsed are ignored
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, parser::ParserConfig};
+    ///
+    /// let text = "John 3:16 For God so loved the world...\nJohn 3:17 For God sent not...\nJohn 3:16 For God so loved the world...";
+    /// let duplicates = Bible::find_duplicate_verses(text, &ParserConfig::default());
+    ///
+    /// // print the duplicates
+    /// for (verse, first_line, duplicate_line) in duplicates {
+    ///     println!("{} is defined on lines {} and {}", verse, first_line, duplicate_line); // John 3:16, 1 and 3
+    /// }
+    /// ```
+    pub fn find_duplicate_verses(text: &str, config: &ParserConfig) -> Vec<(BibleLookup, usize, usize)> {
+        let mut first_lines: HashMap<BibleLookup, usize> = HashMap::new();
+        let mut duplicates = Vec::new();
+
+        for (index, line) in text.lines().enumerate() {
+            let Some((book, chapter, verse, _)) = Self::parse_line(line, config) else {
+                continue;
+            };
+            let lookup = BibleLookup::new(book, chapter, verse);
+            let line_number = index + 1;
+            match first_lines.get(&lookup) {
+                Some(first_line) => duplicates.push((lookup, *first_line, line_number)),
+                None => {
+                    first_lines.insert(lookup, line_number);
+                }
+            }
+        }
+
+        duplicates
+    }
+
+    #[doc(hidden)]
+    fn from_parts(translation: Translation, verses: VerseMap, paragraph_starts: ParagraphStarts) -> Self {
+        Self {
+            translation,
+            verses,
+            paragraph_starts,
+            chapter_cache: ChapterCache::default(),
+            history: History::default(),
+            max_range: Self::DEFAULT_MAX_RANGE,
+        }
+    }
+
+    /// Create a new Bible instance with the specified translation
+    pub fn new(translation: Translation) -> Result<Self, BibleLibError> {
+        let text = translation.get_text()?;
+        let (verses, paragraph_starts) = Self::parse_text(&text, &ParserConfig::default())?;
+        Ok(Self::from_parts(translation, verses, paragraph_starts))
+    }
+
+    /// Create a new Bible instance with the specified translation, checking that the parsed data looks complete
+    /// Returns `InvalidCustomTranslationFile` if no verses were parsed, or any chapter has no verse with text
+    /// (i.e. a file using a different separator, so every line parses as a reference without text)
+    /// Translations that leave out individual verses are accepted (see `chapter_gaps`)
+    /// Useful for catching broken custom translation files at construction; the built-in translations always pass
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, Translation, error::BibleLibError};
+    ///
+    /// // get the bible translation, checking its data
+    /// let bible = Bible::new_validated(Translation::default()).unwrap();
+    /// // print the number of verses
+    /// println!("Loaded {} verses", bible.verse_count());
+    ///
+    /// // a custom translation whose verses have no text is rejected
+    /// let path = std::env::temp_dir().join("bible_lib_new_validated.txt");
+    /// std::fs::write(&path, "John 1:1\nJohn 1:2\nJohn 1:3").unwrap();
+    /// let translation = Translation::Custom { name: "Broken".to_string(), path: path.to_string_lossy().to_string() };
+    /// assert!(matches!(Bible::new_validated(translation), Err(BibleLibError::InvalidCustomTranslationFile)));
+    /// ```
+    pub fn new_validated(translation: Translation) -> Result<Self, BibleLibError> {
+        let bible = Self::new(translation)?;
+        // individual verses may be left out or left empty, but not a whole chapter
+        let complete = !bible.verses.is_empty() && bible.verses.values().all(|chapters| {
+            !chapters.is_empty() && chapters.values().all(|verses| verses.values().any(|text| Self::has_content(text)))
+        });
+        if !complete {
+            return Err(BibleLibError::InvalidCustomTranslationFile);
+        }
+        Ok(bible)
+    }
+
+    /// Create a new Bible instance with the specified translation, along with how long parsing its text took
+    /// The time spent reading a custom translation from the filesystem is not included
+    /// Useful for diagnosing slow startup or comparing translations of different sizes
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, Translation};
+    ///
+    /// // get the bible translation and the time it took to parse
+    /// let (bible, elapsed) = Bible::new_timed(Translation::default()).unwrap();
+    ///
+    /// // print the parse time
+    /// println!("Parsed {} in {:?}", bible.get_translation(), elapsed);
+    /// ```
+    pub fn new_timed(translation: Translation) -> Result<(Self, std::time::Duration), BibleLibError> {
+        let text = translation.get_text()?;
+        let start = std::time::Instant::now();
+        let (verses, paragraph_starts) = Self::parse_text(&text, &ParserConfig::default())?;
+        let elapsed = start.elapsed();
+        Ok((Self::from_parts(translation, verses, paragraph_starts), elapsed))
+    }
+
+    /// Create a new Bible instance from the text of a translation
+    /// `name` is strictly for display purposes
+    /// `config` controls how each line is parsed (see `ParserConfig`)
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, BibleLookup, parser::ParserConfig};
+    ///
+    /// // parse a translation that separates the reference and the text with a tab
+    /// let config = ParserConfig { separator: Some("\t".to_string()), ..Default::default() };
+    /// let bible = Bible::from_text("My Translation", "John 3:16\tFor God so loved the world...", &config).unwrap();
+    ///
+    /// // get the verse text
+    /// let verse = bible.get_verse(BibleLookup::new("John", 3, 16), false).unwrap();
+    /// println!("John 3:16: {}", verse);
+    /// ```
+    pub fn from_text<S: Into<String>>(name: S, text: &str, config: &ParserConfig) -> Result<Self, BibleLibError> {
+        let (verses, paragraph_starts) = Self::parse_text(text, config)?;
+        Ok(Self::from_parts(Translation::Custom { name: name.into(), path: String::new() }, verses, paragraph_starts))
+    }
+
+    /// Create a new Bible instance from a translation file
+    /// This is the same as `Bible::new` with `Translation::Custom`, but with a custom `ParserConfig`
+    /// `name` is strictly for display purposes
+    pub fn from_path<S: Into<String>, P: Into<String>>(name: S, path: P, config: &ParserConfig) -> Result<Self, BibleLibError> {
+        let translation = Translation::Custom { name: name.into(), path: path.into() };
+        let text = translation.get_text()?;
+        let (verses, paragraph_starts) = Self::parse_text(&text, config)?;
+        Ok(Self::from_parts(translation, verses, paragraph_starts))
+    }
+
+    /// Remove a single verse from the Bible, returning its text
+    /// Chapters left without verses and books left without chapters are removed as well,
+    /// so queries for them return `ChapterNotFound` / `BookNotFound` just like data that never existed
+    /// Returns `InvalidVerseFormat` if the lookup is a range
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, BibleLookup, Translation};
+    ///
+    /// // get the bible translation
+    /// let mut bible = Bible::new(Translation::default()).unwrap();
+    ///
+    /// // remove John 3:16
+    /// let text = bible.remove_verse(&BibleLookup::new("John", 3, 16)).unwrap();
+    /// println!("Removed: {}", text);
+    /// ```
+    pub fn remove_verse(&mut self, lookup: &BibleLookup) -> Result<String, BibleLibError> {
+        if lookup.thru_verse.is_some() {
+            return Err(BibleLibError::InvalidVerseFormat);
+        }
+        let Some(book) = self.stored_book(&lookup.book).cloned() else {
+            return Err(BibleLibError::BookNotFound);
+        };
+        let Some(chapters) = self.verses.get_mut(&book) else {
+            return Err(BibleLibError::BookNotFound);
+        };
+        let Some(verses) = chapters.get_mut(&lookup.chapter) else {
+            return Err(BibleLibError::ChapterNotFound);
+        };
+        let Some(text) = verses.remove(&lookup.verse) else {
+            return Err(BibleLibError::VerseNotFound);
+        };
+        self.chapter_cache.clear();
+
+        // prune the chapter and book if they are now empty
+        if verses.is_empty() {
+            chapters.remove(&lookup.chapter);
+        }
+        if chapters.is_empty() {
+            self.verses.remove(&book);
+        }
+        Ok(text)
+    }
+
+    /// Remove an entire chapter from the Bible
+    /// The book is removed as well if it is left without chapters (see `remove_verse`)
+    pub fn remove_chapter(&mut self, book: &str, chapter: u32) -> Result<(), BibleLibError> {
+        let Some(book) = self.stored_book(book).cloned() else {
+            return Err(BibleLibError::BookNotFound);
+        };
+        let Some(chapters) = self.verses.get_mut(&book) else {
+            return Err(BibleLibError::BookNotFound);
+        };
+        if chapters.remove(&chapter).is_none() {
+            return Err(BibleLibError::ChapterNotFound);
+        }
+        self.chapter_cache.clear();
+
+        // prune the book if it is now empty
+        if chapters.is_empty() {
+            self.verses.remove(&book);
+        }
+        Ok(())
+    }
+
+    /// Remove an entire book from the Bible
+    pub fn remove_book(&mut self, book: &str) -> Result<(), BibleLibError> {
+        let Some(book) = self.stored_book(book).cloned() else {
+            return Err(BibleLibError::BookNotFound);
+        };
+        match self.verses.remove(&book) {
+            Some(_) => {
+                self.chapter_cache.clear();
+                Ok(())
+            }
+            None => Err(BibleLibError::BookNotFound),
+        }
+    }
+
+    /// Enable recording of the most recently accessed verses, keeping at most `capacity` of them
+    /// Every successful `get_verse` call is recorded; see `recent` to retrieve them
+    /// History is disabled by default
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, BibleLookup, Translation};
+    ///
+    /// // get the bible translation, remembering the last 10 verses
+    /// let bible = Bible::new(Translation::default()).unwrap().with_history(10);
+    ///
+    /// // look up a verse
+    /// bible.get_verse(BibleLookup::new("John", 3, 16), false).unwrap();
+    ///
+    /// assert_eq!(bible.recent(), vec![BibleLookup::new("John", 3, 16)]);
+    /// ```
+    pub fn with_history(mut self, capacity: usize) -> Self {
+        self.history = History::new(capacity);
+        self
+    }
+
+    /// Set the maximum number of verses a single range passed to `get_verse` may cover
+    /// (`DEFAULT_MAX_RANGE` by default)
+    /// Longer ranges return `RangeTooLong`, so untrusted input can't request huge spans of text
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, BibleLookup, Translation, error::BibleLibError};
+    ///
+    /// // get the bible translation, allowing at most 10 verses per range
+    /// let bible = Bible::new(Translation::default()).unwrap().with_max_range(10);
+    ///
+    /// // a range of 21 verses is rejected
+    /// let result = bible.get_verse(BibleLookup::new_range("John", 3, 1, 21), false);
+    /// assert!(matches!(result, Err(BibleLibError::RangeTooLong(10))));
+    /// ```
+    pub fn with_max_range(mut self, max: usize) -> Self {
+        self.max_range = max;
+        self
+    }
+
+    /// Get the most recently accessed verses, oldest first
+    /// Always empty unless history was enabled with `with_history`
+    pub fn recent(&self) -> Vec<BibleLookup> {
+        self.history.lookups()
+    }
+
+    /// Get the current translation of the Bible instance
+    pub fn get_translation(&self) -> &Translation {
+        &self.translation
+    }
+
+    /// Get the text of every verse, keyed by book, then chapter, then verse
+    /// Use `remove_verse`, `remove_chapter` and `remove_book` to modify the Bible,
+    /// so the output of `get_chapter` is never cached from outdated text
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    ///
+    /// // John 11:35 is the shortest verse
+    /// assert_eq!(bible.verses()["john"][&11][&35], "Jesus wept.");
+    /// ```
+    pub fn verses(&self) -> &HashMap<String /* Book */,
+                            HashMap<u32 /* Chapter */,
+                            HashMap<u32 /* Verse */, String /* Text */>>> {
+        &self.verses
+    }
+
+    #[doc(hidden)]
+    fn replace_superscript(s: String) -> String {
+        s.chars().map(|c| {
             match c {
                 '0' => '⁰',
                 '1' => '¹',
@@ -400,180 +1339,1985 @@ impl Bible {
                 '9' => '⁹',
                 _ => c,
             }
-        }).collect()
+        }).collect()
+    }
+
+    /// Get the text of a verse or range of verses
+    /// `use_superscripts` adds superscript verse numbers for better readability
+    /// Verses of a range are joined with a single space
+    /// Returns an error if the verse or chapter is not found
+    /// (for ranges, `VerseNotFoundAt` reports the first missing verse,
+    /// `RangeExceedsChapter` reports a range running past the end of the chapter,
+    /// `RangeTooLong` reports a range longer than allowed by `with_max_range`,
+    /// and `InvalidVerseFormat` reports a range ending before it starts)
+    /// Ranges are limited to `DEFAULT_MAX_RANGE` verses unless changed with `with_max_range`,
+    /// so long ranges like `Psalms 1:1-150:6` return `RangeTooLong` by default
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, BibleLookup, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::AmericanStandard).unwrap();
+    /// // create a lookup for John 3:16
+    /// let lookup = BibleLookup::new("John", 3, 16);
+    /// // get the verse text
+    /// let verse = bible.get_verse(lookup, false).unwrap();
+    ///
+    /// // print the verse text
+    /// println!("John 3:16: {}", verse);
+    /// ```
+    pub fn get_verse(&self, lookup: BibleLookup, use_superscripts: bool) -> Result<String, BibleLibError> {
+        let text = self.lookup_verse(&lookup, use_superscripts)?;
+        self.history.push(&lookup);
+        Ok(text)
+    }
+
+    /// Check if looked up text has anything besides whitespace and superscript verse numbers
+    #[doc(hidden)]
+    fn has_content(text: &str) -> bool {
+        text.chars().any(|c| !c.is_whitespace() && !"⁰¹²³⁴⁵⁶⁷⁸⁹".contains(c))
+    }
+
+    /// Get the text of a verse or range of verses, falling back to other translations
+    /// when it is missing (or left empty) in this one
+    /// The fallbacks are tried in order, and the translation that supplied the text is returned with it
+    /// Returns the error from this Bible if none of the translations have the verse
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, BibleLookup, Translation};
+    ///
+    /// // get the bible translations
+    /// let bible = Bible::new(Translation::AmericanStandard).unwrap();
+    /// let fallback = Bible::new(Translation::KingJames).unwrap();
+    /// // Matthew 17:21 is omitted from the American Standard Version
+    /// let lookup = BibleLookup::new("Matthew", 17, 21);
+    /// let (verse, translation) = bible.get_verse_with_fallback(&lookup, &[fallback], true).unwrap();
+    ///
+    /// // print the verse text and where it came from
+    /// println!("Matthew 17:21 ({}): {}", translation, verse);
+    /// assert_eq!(translation, Translation::KingJames);
+    /// ```
+    pub fn get_verse_with_fallback(&self, lookup: &BibleLookup, fallbacks: &[Bible], use_superscripts: bool) -> Result<(String, Translation), BibleLibError> {
+        let mut error = None;
+        for bible in std::iter::once(self).chain(fallbacks) {
+            match bible.lookup_verse(lookup, use_superscripts) {
+                Ok(text) if Self::has_content(&text) => {
+                    self.history.push(lookup);
+                    return Ok((text, bible.translation.clone()));
+                }
+                Ok(_) => {
+                    error.get_or_insert(BibleLibError::VerseNotFound);
+                }
+                Err(e) => {
+                    error.get_or_insert(e);
+                }
+            }
+        }
+        Err(error.unwrap_or(BibleLibError::VerseNotFound))
+    }
+
+    /// Get the text of a verse or range of verses, falling back to the whole chapter when the
+    /// verse is missing (or left empty) in this translation but its chapter exists
+    /// Use this only when a "closest available" result is wanted: the returned text
+    /// may be the entire chapter rather than the requested verse
+    /// Other errors (i.e. `BookNotFound` or `ChapterNotFound`) are returned as-is
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, BibleLookup, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::AmericanStandard).unwrap();
+    /// // Matthew 17:21 is omitted from the American Standard Version
+    /// let lookup = BibleLookup::new("Matthew", 17, 21);
+    /// let text = bible.get_verse_or_chapter(&lookup, true).unwrap();
+    ///
+    /// // the whole chapter is returned instead
+    /// assert_eq!(text, bible.get_chapter("Matthew", 17, true).unwrap());
+    /// ```
+    pub fn get_verse_or_chapter(&self, lookup: &BibleLookup, use_superscripts: bool) -> Result<String, BibleLibError> {
+        match self.lookup_verse(lookup, use_superscripts) {
+            Ok(text) if Self::has_content(&text) => {
+                self.history.push(lookup);
+                Ok(text)
+            }
+            Ok(_) | Err(BibleLibError::VerseNotFound) | Err(BibleLibError::VerseNotFoundAt(_)) => {
+                self.get_chapter(&lookup.book, lookup.chapter, use_superscripts)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Get the text of a verse or range of verses with each verse on its own line, labeled with its number
+    /// (i.e. `16 For God so loved...` followed by `17 For God sent not...`)
+    /// Ranges spanning chapters label each verse with its chapter as well (i.e. `4:1 When therefore...`)
+    /// Returns the same errors as `get_verse`
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, BibleLookup, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    /// // get John 3:16-17 as labeled lines
+    /// let lines = bible.get_verse_lines(&BibleLookup::new_range("John", 3, 16, 17)).unwrap();
+    ///
+    /// assert_eq!(lines.lines().count(), 2);
+    /// assert!(lines.starts_with("16 "));
+    /// // print the verses
+    /// println!("{}", lines);
+    /// ```
+    pub fn get_verse_lines(&self, lookup: &BibleLookup) -> Result<String, BibleLibError> {
+        // validate the lookup the same way `get_verse` does
+        self.lookup_verse(lookup, false)?;
+        let spans_chapters = lookup.thru_chapter.is_some_and(|thru_chapter| thru_chapter != lookup.chapter);
+
+        let lines = self.expand_existing(lookup).into_iter()
+            .map(|verse| {
+                let text = &self.verses[&verse.book][&verse.chapter][&verse.verse];
+                if spans_chapters {
+                    format!("{}:{} {}", verse.chapter, verse.verse, text)
+                } else {
+                    format!("{} {}", verse.verse, text)
+                }
+            })
+            .collect::<Vec<String>>();
+        self.history.push(lookup);
+        Ok(lines.join("\n"))
+    }
+
+    /// Get the text of a passage, which may span chapters and books
+    /// `use_superscripts` adds superscript verse numbers for better readability
+    /// Returns an error if the first or last verse of the passage is not found,
+    /// `InvalidVerseFormat` if the passage ends before it starts,
+    /// and `RangeTooLong` if it covers more verses than allowed by `with_max_range`
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, Translation, passage::Passage};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    /// // the last verse of Malachi and the first of Matthew
+    /// let passage: Passage = "Malachi 4:6 to Matthew 1:1".parse().unwrap();
+    /// // get the passage text
+    /// let text = bible.get_passage(&passage, true).unwrap();
+    ///
+    /// // print the passage text
+    /// println!("{}: {}", passage, text);
+    ///
+    /// // the King James Version names the book `psalms`, but `psalm` still finds it
+    /// let kjv = Bible::new(Translation::KingJames).unwrap();
+    /// let passage: Passage = "Psalm 23:6 to Psalm 24:1".parse().unwrap();
+    /// assert!(kjv.get_passage(&passage, false).is_ok());
+    /// ```
+    pub fn get_passage(&self, passage: &Passage, use_superscripts: bool) -> Result<String, BibleLibError> {
+        // report missing verses the same way single verse lookups do
+        self.verse_str(&passage.start)?;
+        self.verse_str(&passage.end)?;
+
+        let ordered = self.ordered_verses();
+        // compare against the name the book is stored under (i.e. `psalm` may be stored as `psalms`)
+        let position = |target: &BibleLookup| {
+            let book = self.stored_book(&target.book)?;
+            let target = BibleLookup::new(book.as_str(), target.chapter, target.verse);
+            ordered.iter().position(|(lookup, _)| *lookup == target)
+        };
+        let (Some(start), Some(end)) = (position(&passage.start), position(&passage.end)) else {
+            return Err(BibleLibError::VerseNotFound);
+        };
+        if start > end {
+            return Err(BibleLibError::InvalidVerseFormat);
+        }
+        if end - start + 1 > self.max_range {
+            return Err(BibleLibError::RangeTooLong(self.max_range));
+        }
+
+        let text = ordered[start..=end].iter()
+            .map(|(lookup, text)| {
+                if use_superscripts {
+                    format!("{}{}", Self::replace_superscript(lookup.verse.to_string()), text)
+                } else {
+                    text.to_string()
+                }
+            })
+            .collect::<Vec<String>>();
+        Ok(text.join(" "))
+    }
+
+    #[doc(hidden)]
+    fn lookup_verse(&self, lookup: &BibleLookup, use_superscripts: bool) -> Result<String, BibleLibError> {
+        // multiple verse lookup
+        if let Some(thru_verse) = lookup.thru_verse {
+            let mut verse_text = String::new();
+
+            let Some(chapters) = self.stored_book(&lookup.book).map(|book| &self.verses[book]) else {
+                return Err(BibleLibError::BookNotFound);
+            };
+            let thru_chapter = lookup.thru_chapter.unwrap_or(lookup.chapter);
+
+            // reversed ranges (i.e. `John 3:18-16`) are invalid rather than empty
+            if (thru_chapter, thru_verse) < (lookup.chapter, lookup.verse) {
+                return Err(BibleLibError::InvalidVerseFormat);
+            }
+
+            // make sure the range doesn't run past the end of its last chapter
+            if let Some(max_verse) = chapters.get(&thru_chapter).and_then(|verses| verses.keys().max())
+                && thru_verse > *max_verse {
+                return Err(BibleLibError::RangeExceedsChapter(*max_verse));
+            }
+
+            // make sure the range doesn't cover more verses than allowed
+            let length = chapters.iter()
+                .filter(|(chapter, _)| (lookup.chapter..=thru_chapter).contains(*chapter))
+                .map(|(chapter, verses)| {
+                    let first_verse = if *chapter == lookup.chapter { lookup.verse } else { 1 };
+                    let last_verse = if *chapter == thru_chapter {
+                        thru_verse
+                    } else {
+                        verses.keys().max().copied().unwrap_or(0)
+                    };
+                    (last_verse as usize + 1).saturating_sub(first_verse as usize)
+                })
+                .sum::<usize>();
+            if length > self.max_range {
+                return Err(BibleLibError::RangeTooLong(self.max_range));
+            }
+
+            // iterate through the chapters (only one unless the range spans chapters)
+            for chapter in lookup.chapter..=thru_chapter {
+                let Some(verses) = chapters.get(&chapter) else {
+                    return Err(BibleLibError::ChapterNotFound);
+                };
+                // the range starts at `verse` in the first chapter and ends at `thru_verse` in the last
+                let first_verse = if chapter == lookup.chapter { lookup.verse } else { 1 };
+                let last_verse = if chapter == thru_chapter {
+                    thru_verse
+                } else {
+                    verses.keys().max().copied().unwrap_or(0)
+                };
+
+                // iterate through the verses
+                for verse in first_verse..=last_verse {
+                    let Some(text) = verses.get(&verse) else {
+                        return Err(BibleLibError::VerseNotFoundAt(verse));
+                    };
+
+                    if use_superscripts {
+                        verse_text.push_str(&format!("{}{} ", Self::replace_superscript(verse.to_string()), text));
+                    } else {
+                        verse_text.push_str(&format!("{} ", text));
+                    }
+                }
+            }
+            return Ok(verse_text.trim().to_string());
+        }
+        
+        // single verse lookup
+        let Some(chapters) = self.stored_book(&lookup.book).map(|book| &self.verses[book]) else {
+            return Err(BibleLibError::BookNotFound);
+        };
+        let Some(verses) = chapters.get(&lookup.chapter) else {
+            return Err(BibleLibError::ChapterNotFound);
+        };
+        let Some(text) = verses.get(&lookup.verse) else {
+            return Err(BibleLibError::VerseNotFound);
+        };
+
+        if use_superscripts {
+            Ok(format!("{}{}", Self::replace_superscript(lookup.verse.to_string()), text))
+        } else {
+            Ok(text.to_string())
+        }
+    }
+
+    /// Get the text of a verse or range of verses shortened to at most `max_chars` characters for previews
+    /// Longer text is cut at a word boundary and ends with `…` (which counts towards `max_chars`)
+    /// A single word longer than the limit is cut mid-word, always on a character boundary
+    /// Returns the same errors as `get_verse`
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, BibleLookup, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    /// // get a preview of John 3:16
+    /// let preview = bible.get_verse_preview(&BibleLookup::new("John", 3, 16), 30).unwrap();
+    ///
+    /// assert!(preview.chars().count() <= 30);
+    /// assert!(preview.ends_with('…'));
+    /// // print the preview
+    /// println!("{}", preview); // For God so loved the world,…
+    /// ```
+    pub fn get_verse_preview(&self, lookup: &BibleLookup, max_chars: usize) -> Result<String, BibleLibError> {
+        let text = self.get_verse(lookup.clone(), false)?;
+        if text.chars().count() <= max_chars {
+            return Ok(text);
+        }
+        if max_chars == 0 {
+            return Ok(String::new());
+        }
+
+        // leave room for the ellipsis, cutting on a character boundary
+        let cut = text.char_indices().nth(max_chars - 1).map_or(text.len(), |(index, _)| index);
+        let prefix = &text[..cut];
+        // back up to the last word boundary, unless the first word alone is too long
+        let prefix = match prefix.rfind(char::is_whitespace) {
+            Some(index) if !text[cut..].starts_with(char::is_whitespace) => &prefix[..index],
+            _ => prefix,
+        };
+        Ok(format!("{}…", prefix.trim_end()))
+    }
+
+    /// Get a borrow of the stored text of a single verse without allocating
+    /// Returns `InvalidVerseFormat` if the lookup is a range, as ranges have to be joined
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, BibleLookup, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    /// // create a lookup for John 3:16
+    /// let lookup = BibleLookup::new("John", 3, 16);
+    /// // get the verse text
+    /// let verse: &str = bible.verse_str(&lookup).unwrap();
+    ///
+    /// // print the verse text
+    /// println!("John 3:16: {}", verse);
+    ///
+    /// // lookups built by hand don't need a lowercase book
+    /// let lookup = BibleLookup { book: "John".to_string(), chapter: 3, verse: 16, thru_verse: None, thru_chapter: None };
+    /// assert_eq!(bible.verse_str(&lookup).unwrap(), verse);
+    /// ```
+    pub fn verse_str(&self, lookup: &BibleLookup) -> Result<&str, BibleLibError> {
+        if lookup.thru_verse.is_some() {
+            return Err(BibleLibError::InvalidVerseFormat);
+        }
+        let Some(chapters) = self.stored_book(&lookup.book).map(|book| &self.verses[book]) else {
+            return Err(BibleLibError::BookNotFound);
+        };
+        let Some(verses) = chapters.get(&lookup.chapter) else {
+            return Err(BibleLibError::ChapterNotFound);
+        };
+        let Some(text) = verses.get(&lookup.verse) else {
+            return Err(BibleLibError::VerseNotFound);
+        };
+        Ok(text)
+    }
+
+    /// Get the words of a verse (or range of verses) as lowercase tokens with punctuation removed
+    /// See `search::tokenize` for how the text is split
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, BibleLookup, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    /// // get the tokens of John 11:35
+    /// let tokens = bible.verse_tokens(&BibleLookup::new("John", 11, 35)).unwrap();
+    ///
+    /// // print the tokens
+    /// println!("Tokens: {:?}", tokens); // ["jesus", "wept"]
+    /// ```
+    pub fn verse_tokens(&self, lookup: &BibleLookup) -> Result<Vec<String>, BibleLibError> {
+        let text = self.get_verse(lookup.clone(), false)?;
+        Ok(search::tokenize(&text))
+    }
+
+    /// Get a verse formatted as a line of a translation file (`Book Chapter:Verse Text`)
+    /// Useful for diffing against the original file or re-exporting verses
+    /// Returns `InvalidVerseFormat` if the lookup is a range
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, BibleLookup, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    /// // get the line for John 3:16
+    /// let line = bible.source_line(&BibleLookup::new("John", 3, 16)).unwrap();
+    ///
+    /// // print the line
+    /// println!("{}", line); // John 3:16 For God so loved the world...
+    ///
+    /// // the line uses the book name of the translation, which is `Psalms` in the King James Version
+    /// let kjv = Bible::new(Translation::KingJames).unwrap();
+    /// let line = kjv.source_line(&BibleLookup::new("Psalm", 23, 1)).unwrap();
+    /// assert!(line.starts_with("Psalms 23:1 "));
+    /// ```
+    pub fn source_line(&self, lookup: &BibleLookup) -> Result<String, BibleLibError> {
+        let text = self.verse_str(lookup)?;
+        // write the book the way the translation does (i.e. `psalm` may be stored as `psalms`)
+        let book = self.stored_book(&lookup.book).ok_or(BibleLibError::BookNotFound)?;
+        Ok(format!("{} {}", BibleLookup::new(book.as_str(), lookup.chapter, lookup.verse), text))
+    }
+
+    /// Format a verse or range of verses as a block of text ready for sharing:
+    /// the verse text, a blank line, then the reference and translation
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, BibleLookup, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::KingJames).unwrap();
+    /// // format John 3:16 for sharing
+    /// let share = bible.format_share(&BibleLookup::new("John", 3, 16)).unwrap();
+    ///
+    /// // print the formatted text
+    /// println!("{}", share);
+    /// // For God so loved the world, ...
+    /// //
+    /// // — John 3:16 (King James Version)
+    /// ```
+    pub fn format_share(&self, lookup: &BibleLookup) -> Result<String, BibleLibError> {
+        let text = self.get_verse(lookup.clone(), false)?;
+        Ok(format!("{}\n\n— {} ({})", text, lookup, self.translation))
+    }
+
+    /// Get the text of a verse (or range of verses) with every case-insensitive occurrence
+    /// of `term` wrapped in the `open` and `close` markers
+    /// The markers can be anything, such as ANSI colors, markdown (`**`) or HTML tags
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, BibleLookup, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    /// // highlight "world" in John 3:16 with markdown bold
+    /// let text = bible.highlight_in_verse(&BibleLookup::new("John", 3, 16), "world", "**", "**").unwrap();
+    ///
+    /// // print the highlighted text
+    /// println!("{}", text); // For God so loved the **world**, ...
+    /// ```
+    pub fn highlight_in_verse(&self, lookup: &BibleLookup, term: &str, open: &str, close: &str) -> Result<String, BibleLibError> {
+        let text = self.get_verse(lookup.clone(), false)?;
+        let mut highlighted = String::new();
+        let mut last = 0;
+        for range in search::find_ignore_case(&text, term) {
+            highlighted.push_str(&text[last..range.start]);
+            highlighted.push_str(open);
+            highlighted.push_str(&text[range.clone()]);
+            highlighted.push_str(close);
+            last = range.end;
+        }
+        highlighted.push_str(&text[last..]);
+        Ok(highlighted)
+    }
+
+    /// Get the text of an entire chapter as a string
+    /// `use_superscripts` adds superscript verse numbers for better readability
+    /// Returns an error if the chapter is not found
+    /// The result is cached, so repeated calls for the same chapter are cheap
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, BibleLookup, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::EnglishedRevised).unwrap();
+    /// // get the text of Isaiah chapter 53
+    /// let chapter_text = bible.get_chapter("Isaiah", 53, true).unwrap();
+    ///
+    /// // print the chapter text
+    /// println!("Isaiah 53: {}", chapter_text);
+    ///
+    /// // the King James Version names the book `psalms`, but `psalm` still finds it
+    /// let kjv = Bible::new(Translation::KingJames).unwrap();
+    /// assert_eq!(kjv.get_chapter("Psalm", 23, false).unwrap(), kjv.get_chapter("Psalms", 23, false).unwrap());
+    /// ```
+    pub fn get_chapter(&self, book: &str, chapter: u32, use_superscripts: bool) -> Result<String, BibleLibError> {
+        let Some(book) = self.stored_book(book).cloned() else {
+            return Err(BibleLibError::BookNotFound);
+        };
+        // chapters are cached after the first call since rebuilding them requires sorting and formatting
+        // every verse, while a cached chapter only has to be copied
+        if let Some(chapter_text) = self.chapter_cache.get(&book, chapter, use_superscripts) {
+            return Ok(chapter_text);
+        }
+
+        let mut chapter_text = String::new();
+        // sort the verses by verse number
+        let Some(chapters) = self.verses.get(&book) else {
+            return Err(BibleLibError::BookNotFound);
+        };
+        let Some(verses) = chapters.get(&chapter) else {
+            return Err(BibleLibError::ChapterNotFound);
+        };
+        let mut verses = verses.iter().collect::<Vec<(&u32, &String)>>();
+        verses.sort_by(|a, b| a.0.cmp(b.0));
+        for (verse, text) in verses {
+            let verse_designation = Self::replace_superscript(verse.to_string());
+            if use_superscripts {
+                chapter_text.push_str(&format!("{}{} ", verse_designation, text));
+            } else {
+                chapter_text.push_str(&format!("{} ", text));
+            }
+        }
+        self.chapter_cache.insert(&book, chapter, use_superscripts, &chapter_text);
+        Ok(chapter_text)
+    }
+
+    /// Get the verses of an entire chapter grouped into paragraphs, each a list of (verse number, text) in order
+    /// Paragraphs are marked in the translation text by a blank line or a verse starting with `¶`
+    /// Translations without paragraph markers return the whole chapter as a single paragraph
+    /// Returns an error if the chapter is not found
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, parser::ParserConfig};
+    ///
+    /// let text = "Jude 1:1 Jude, a servant...\nJude 1:2 Mercy unto you...\n\nJude 1:3 Beloved, when...";
+    /// let bible = Bible::from_text("Paragraphs", text, &ParserConfig::default()).unwrap();
+    ///
+    /// // get the paragraphs of Jude 1
+    /// let paragraphs = bible.chapter_paragraphs("Jude", 1).unwrap();
+    /// assert_eq!(paragraphs.len(), 2);
+    /// ```
+    pub fn chapter_paragraphs(&self, book: &str, chapter: u32) -> Result<Vec<Vec<(u32, String)>>, BibleLibError> {
+        let Some(book) = self.stored_book(book).cloned() else {
+            return Err(BibleLibError::BookNotFound);
+        };
+        let mut paragraphs: Vec<Vec<(u32, String)>> = Vec::new();
+        for (verse, text) in self.get_chapter_verses(&book, chapter)? {
+            let starts_paragraph = self.paragraph_starts.contains(&(book.clone(), chapter, verse));
+            match paragraphs.last_mut() {
+                Some(paragraph) if !starts_paragraph => paragraph.push((verse, text)),
+                _ => paragraphs.push(vec![(verse, text)]),
+            }
+        }
+        Ok(paragraphs)
+    }
+
+    /// Render an entire book for printing: the book title, then each chapter's heading and verses in order
+    /// `style` controls the verse numbers, the layout of the verses and the chapter headings (see `PrintStyle`)
+    /// Returns `BookNotFound` if the book is not found
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, Translation, print::PrintStyle};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    /// // render Jude with one verse per line
+    /// let style = PrintStyle { line_per_verse: true, ..Default::default() };
+    /// let text = bible.book_to_print("Jude", style).unwrap();
+    ///
+    /// assert!(text.starts_with("Jude\n\nChapter 1\n\n1 "));
+    /// // print the book
+    /// println!("{}", text);
+    /// ```
+    pub fn book_to_print(&self, book: &str, style: PrintStyle) -> Result<String, BibleLibError> {
+        let mut chapters = self.get_chapters(book)?;
+        chapters.sort();
+
+        // title the book with the name the translation uses
+        let title = self.stored_book(book).ok_or(BibleLibError::BookNotFound)?;
+        let mut sections = vec![BibleLookup::capitalize_book(title)];
+        for chapter in chapters {
+            sections.push(style.chapter_heading.replace("{}", &chapter.to_string()));
+            let paragraphs = self.chapter_paragraphs(book, chapter)?;
+            let verses = paragraphs.into_iter()
+                .map(|paragraph| {
+                    let separator = if style.line_per_verse { "\n" } else { " " };
+                    paragraph.into_iter()
+                        .map(|(verse, text)| {
+                            if style.superscripts {
+                                format!("{}{}", Self::replace_superscript(verse.to_string()), text)
+                            } else {
+                                format!("{} {}", verse, text)
+                            }
+                        })
+                        .collect::<Vec<String>>()
+                        .join(separator)
+                })
+                .collect::<Vec<String>>();
+            // paragraphs are separated by a blank line when verses are joined
+            sections.push(verses.join(if style.line_per_verse { "\n" } else { "\n\n" }));
+        }
+        Ok(sections.join("\n\n"))
+    }
+
+    /// Get every verse of a book as (reference, text) pairs in order (i.e. `("John 3:16", "For God so loved...")`)
+    /// Useful for exporting a book to a table or CSV
+    /// Returns an error if the book is not found
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    /// // get the verses of Jude
+    /// let pairs = bible.book_as_pairs("Jude").unwrap();
+    ///
+    /// // print each verse
+    /// for (reference, text) in pairs {
+    ///     println!("{}: {}", reference, text);
+    /// }
+    /// ```
+    pub fn book_as_pairs(&self, book: &str) -> Result<Vec<(String, String)>, BibleLibError> {
+        let mut chapters = self.get_chapters(book)?;
+        chapters.sort();
+
+        // reference the verses by the name the translation uses
+        let book = self.stored_book(book).ok_or(BibleLibError::BookNotFound)?;
+        let mut pairs = Vec::new();
+        for chapter in chapters {
+            for (verse, text) in self.get_chapter_verses(book, chapter)? {
+                pairs.push((BibleLookup::new(book.as_str(), chapter, verse).to_string(), text));
+            }
+        }
+        Ok(pairs)
+    }
+
+    /// Get every verse in canonical order (books, then chapters, then verses)
+    #[doc(hidden)]
+    fn ordered_verses(&self) -> Vec<(BibleLookup, &String)> {
+        let mut ordered = Vec::new();
+        for book in self.get_books_ordered() {
+            let chapters = &self.verses[&book];
+            let mut chapter_numbers = chapters.keys().copied().collect::<Vec<u32>>();
+            chapter_numbers.sort();
+            for chapter in chapter_numbers {
+                let verses = &chapters[&chapter];
+                let mut verse_numbers = verses.keys().copied().collect::<Vec<u32>>();
+                verse_numbers.sort();
+                for verse in verse_numbers {
+                    ordered.push((BibleLookup::new(book.as_str(), chapter, verse), &verses[&verse]));
+                }
+            }
+        }
+        ordered
+    }
+
+    /// Get the whole Bible as a nested, fully ordered structure:
+    /// books in canonical order, each with its chapters in order, each with its verses (number, text) in order
+    /// Useful for handing straight to a template engine
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    ///
+    /// // print the number of chapters of each book
+    /// for (book, chapters) in bible.to_ordered() {
+    ///     println!("{}: {} chapters", book, chapters.len());
+    /// }
+    /// ```
+    pub fn to_ordered(&self) -> Vec<OrderedBook> {
+        self.get_books_ordered().into_iter()
+            .map(|book| {
+                let mut chapter_numbers = self.verses[&book].keys().copied().collect::<Vec<u32>>();
+                chapter_numbers.sort();
+                let chapters = chapter_numbers.into_iter()
+                    .map(|chapter| (chapter, self.get_chapter_verses(&book, chapter).unwrap_or_default()))
+                    .collect();
+                (book, chapters)
+            })
+            .collect()
+    }
+
+    /// Export every verse as JSON Lines: one JSON object per verse per line, in canonical order
+    /// (i.e. `{"book":"John","chapter":3,"verse":16,"text":"..."}`)
+    /// Friendlier for streaming into line-oriented tools than a single JSON document
+    /// Requires the `serde` feature to be enabled
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, parser::ParserConfig};
+    ///
+    /// let text = "John 3:16 For God so loved the world...\nJohn 3:17 For God sent not his Son...";
+    /// let bible = Bible::from_text("Example", text, &ParserConfig::default()).unwrap();
+    ///
+    /// // export the verses
+    /// let jsonl = bible.to_jsonl();
+    /// assert_eq!(jsonl.lines().count(), 2);
+    /// assert!(jsonl.starts_with(r#"{"book":"John","chapter":3,"verse":16,"text":"#));
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn to_jsonl(&self) -> String {
+        self.ordered_verses().into_iter()
+            .map(|(lookup, text)| {
+                // written out by hand to keep the fields in reference order
+                let book = serde_json::Value::from(BibleLookup::capitalize_book(&lookup.book));
+                let text = serde_json::Value::from(text.as_str());
+                format!("{{\"book\":{},\"chapter\":{},\"verse\":{},\"text\":{}}}\n", book, lookup.chapter, lookup.verse, text)
+            })
+            .collect()
+    }
+
+    /// Search the text of every verse for `query` (case-insensitive)
+    /// Returns the matching verses and their text in canonical order
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    ///
+    /// // search for verses containing "shepherd"
+    /// let results = bible.search("shepherd");
+    /// // print the results
+    /// for (verse, text) in results {
+    ///     println!("{}: {}", verse, text);
+    /// }
+    /// ```
+    pub fn search(&self, query: &str) -> Vec<(BibleLookup, String)> {
+        self.search_with(query, &SearchOptions::default())
+    }
+
+    /// Search the text of every verse for `query` with the given options
+    /// See `SearchOptions` for case-sensitive and accent-insensitive searching
+    /// Returns the matching verses and their text in canonical order
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, Translation, search::SearchOptions};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    ///
+    /// // search for verses containing "LORD" (exact casing)
+    /// let options = SearchOptions { case_sensitive: true, ..Default::default() };
+    /// let results = bible.search_with("LORD", &options);
+    /// // print the number of results
+    /// println!("Found {} verses", results.len());
+    /// ```
+    pub fn search_with(&self, query: &str, options: &SearchOptions) -> Vec<(BibleLookup, String)> {
+        let query = options.normalize(query);
+        if query.is_empty() {
+            return Vec::new();
+        }
+        self.ordered_verses().into_iter()
+            .filter(|(_, text)| options.normalize(text).contains(&query))
+            .map(|(lookup, text)| (lookup, text.to_string()))
+            .collect()
+    }
+
+    /// Search the text of every verse for `query` (case-insensitive), grouping the results by chapter
+    /// Returns (book, chapter, matching verses) groups in canonical order, each verse as (verse number, text)
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    ///
+    /// // search for verses containing "shepherd"
+    /// for (book, chapter, verses) in bible.search_grouped("shepherd") {
+    ///     let numbers = verses.iter().map(|(verse, _)| format!("v{}", verse)).collect::<Vec<String>>();
+    ///     println!("{} {}: {}", book, chapter, numbers.join(", "));
+    /// }
+    /// ```
+    pub fn search_grouped(&self, query: &str) -> Vec<ChapterGroup> {
+        let mut groups: Vec<ChapterGroup> = Vec::new();
+        for (lookup, text) in self.search(query) {
+            match groups.last_mut() {
+                Some((book, chapter, verses)) if *book == lookup.book && *chapter == lookup.chapter => {
+                    verses.push((lookup.verse, text));
+                }
+                _ => groups.push((lookup.book, lookup.chapter, vec![(lookup.verse, text)])),
+            }
+        }
+        groups
+    }
+
+    /// Find the chapter with the most occurrences of `phrase` (case-insensitive)
+    /// Returns the (book, chapter, number of occurrences), or `None` if the phrase is not found
+    /// Ties are broken by canonical order
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    ///
+    /// // find the chapter about love
+    /// let (book, chapter, count) = bible.best_chapter_for("love").unwrap();
+    /// // print the chapter
+    /// println!("{} {} mentions love {} times", book, chapter, count);
+    /// ```
+    pub fn best_chapter_for(&self, phrase: &str) -> Option<(String, u32, usize)> {
+        let mut best: Option<(String, u32, usize)> = None;
+        for (book, chapter, verses) in self.search_grouped(phrase) {
+            let count = verses.iter()
+                .map(|(_, text)| search::find_ignore_case(text, phrase).len())
+                .sum::<usize>();
+            // only replace on a strictly higher count so ties keep the earlier chapter
+            if best.as_ref().is_none_or(|(_, _, best_count)| count > *best_count) {
+                best = Some((book, chapter, count));
+            }
+        }
+        best
+    }
+
+    /// Search for verses containing every one of `terms` (case-insensitive)
+    /// Returns the matching verses and their text in canonical order
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    ///
+    /// // search for verses containing both "faith" and "hope"
+    /// let results = bible.search_all(&["faith", "hope"]);
+    /// // print the results
+    /// for (verse, text) in results {
+    ///     println!("{}: {}", verse, text);
+    /// }
+    /// ```
+    pub fn search_all(&self, terms: &[&str]) -> Vec<(BibleLookup, String)> {
+        let terms = terms.iter().map(|term| term.to_lowercase()).collect::<Vec<String>>();
+        if terms.is_empty() || terms.iter().any(|term| term.is_empty()) {
+            return Vec::new();
+        }
+        self.ordered_verses().into_iter()
+            .filter(|(_, text)| {
+                let text = text.to_lowercase();
+                terms.iter().all(|term| text.contains(term))
+            })
+            .map(|(lookup, text)| (lookup, text.to_string()))
+            .collect()
+    }
+
+    /// Search for verses containing at least one of `terms` (case-insensitive)
+    /// Returns the matching verses and their text in canonical order
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    ///
+    /// // search for verses containing "faith" or "hope"
+    /// let results = bible.search_any(&["faith", "hope"]);
+    /// // print the number of results
+    /// println!("Found {} verses", results.len());
+    /// ```
+    pub fn search_any(&self, terms: &[&str]) -> Vec<(BibleLookup, String)> {
+        let terms = terms.iter()
+            .map(|term| term.to_lowercase())
+            .filter(|term| !term.is_empty())
+            .collect::<Vec<String>>();
+        self.ordered_verses().into_iter()
+            .filter(|(_, text)| {
+                let text = text.to_lowercase();
+                terms.iter().any(|term| text.contains(term))
+            })
+            .map(|(lookup, text)| (lookup, text.to_string()))
+            .collect()
+    }
+
+    /// Search for verses approximately containing `query`, within `max_distance` edits
+    /// (insertions, deletions or substitutions of a single character, ignoring case)
+    /// Useful for finding a half-remembered verse (i.e. "for god so love the world")
+    /// Returns the matching verses, their text and the edit distance, sorted by distance then canonical order
+    /// note: this compares the query against every verse, so it is much slower than `search`
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, parser::ParserConfig};
+    ///
+    /// let text = "John 3:16 For God so loved the world, that he gave his only begotten Son...";
+    /// let bible = Bible::from_text("Fuzzy", text, &ParserConfig::default()).unwrap();
+    ///
+    /// // search for a misremembered phrase
+    /// let results = bible.search_fuzzy("for god so love the world", 2);
+    /// // print the results
+    /// for (verse, _, distance) in results {
+    ///     println!("{} (distance {})", verse, distance); // John 3:16 (distance 1)
+    /// }
+    /// ```
+    pub fn search_fuzzy(&self, query: &str, max_distance: usize) -> Vec<(BibleLookup, String, usize)> {
+        let query = query.to_lowercase().chars().collect::<Vec<char>>();
+        if query.is_empty() {
+            return Vec::new();
+        }
+        let mut results = self.ordered_verses().into_iter()
+            .filter_map(|(lookup, text)| {
+                let lowercase = text.to_lowercase().chars().collect::<Vec<char>>();
+                let distance = search::substring_distance(&query, &lowercase);
+                (distance <= max_distance).then(|| (lookup, text.to_string(), distance))
+            })
+            .collect::<Vec<(BibleLookup, String, usize)>>();
+        // the sort is stable, so verses with the same distance stay in canonical order
+        results.sort_by_key(|(_, _, distance)| *distance);
+        results
+    }
+
+    /// Get every verse whose text ends with the given punctuation, in canonical order
+    /// (i.e. `'?'` for questions or `'!'` for exclamations)
+    /// Trailing whitespace and closing quotation marks are ignored when checking the ending
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    ///
+    /// // find every verse that asks a question
+    /// let questions = bible.verses_matching_punctuation('?');
+    /// // print the number of questions
+    /// println!("{} verses end with a question", questions.len());
+    /// ```
+    pub fn verses_matching_punctuation(&self, ending: char) -> Vec<BibleLookup> {
+        const CLOSING_QUOTES: &[char] = &['"', '\'', '”', '’', '»'];
+        self.ordered_verses().into_iter()
+            .filter(|(_, text)| {
+                let mut text = text.trim_end();
+                if !CLOSING_QUOTES.contains(&ending) {
+                    text = text.trim_end_matches(|c: char| c.is_whitespace() || CLOSING_QUOTES.contains(&c));
+                }
+                text.ends_with(ending)
+            })
+            .map(|(lookup, _)| lookup)
+            .collect()
+    }
+
+    /// Build an inverted index of every word in the Bible for fast repeated searching
+    /// Words are tokenized with `search::tokenize`
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    /// // build the index once
+    /// let index = bible.build_index();
+    ///
+    /// // search it as many times as needed
+    /// println!("'faith' appears in {} verses", index.search("faith").len());
+    /// println!("'hope' appears in {} verses", index.search("hope").len());
+    /// ```
+    pub fn build_index(&self) -> WordIndex {
+        let mut index = WordIndex::default();
+        for (lookup, text) in self.ordered_verses() {
+            let mut words = search::tokenize(text);
+            // only add each verse once per word
+            words.sort();
+            words.dedup();
+            for word in words {
+                index.words.entry(word).or_default().push(lookup.clone());
+            }
+        }
+        index
+    }
+
+    /// Get every distinct word used in the Bible
+    /// Words are lowercased and stripped of punctuation with `search::tokenize`
+    /// The set is computed eagerly over every verse, and can be large (tens of thousands of words)
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    ///
+    /// // get the vocabulary
+    /// let vocabulary = bible.vocabulary();
+    /// assert!(vocabulary.contains("shepherd"));
+    /// println!("{} distinct words", vocabulary.len());
+    /// ```
+    pub fn vocabulary(&self) -> HashSet<String> {
+        self.verses.values()
+            .flat_map(|chapters| chapters.values())
+            .flat_map(|verses| verses.values())
+            .flat_map(|text| search::tokenize(text))
+            .collect()
+    }
+
+    /// Get every distinct word used in the Bible along with the number of times it occurs
+    /// Words are tokenized the same way as `vocabulary`, and the map is computed eagerly
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    ///
+    /// // count the words
+    /// let counts = bible.vocabulary_with_counts();
+    /// println!("'love' occurs {} times", counts.get("love").copied().unwrap_or(0));
+    /// ```
+    pub fn vocabulary_with_counts(&self) -> HashMap<String, usize> {
+        let mut counts = HashMap::new();
+        for text in self.verses.values().flat_map(|chapters| chapters.values()).flat_map(|verses| verses.values()) {
+            for word in search::tokenize(text) {
+                *counts.entry(word).or_insert(0) += 1;
+            }
+        }
+        counts
+    }
+
+    /// Get the most frequent words in a book, or in one of its chapters, sorted from most to least frequent
+    /// Passing `None` for `chapter` covers the whole book
+    /// Words are tokenized with `search::tokenize`, and common words in `search::STOPWORDS` are skipped
+    /// Words with the same count are sorted alphabetically
+    /// Returns an error if the book or chapter is not found
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    ///
+    /// // get the most frequent words in 1 Corinthians 13
+    /// let frequencies = bible.word_frequencies("1 Corinthians", Some(13)).unwrap();
+    /// // print the top 5
+    /// for (word, count) in frequencies.iter().take(5) {
+    ///     println!("{}: {}", word, count);
+    /// }
+    /// ```
+    pub fn word_frequencies(&self, book: &str, chapter: Option<u32>) -> Result<Vec<(String, usize)>, BibleLibError> {
+        let Some(chapters) = self.book_chapters(book) else {
+            return Err(BibleLibError::BookNotFound);
+        };
+        let texts = match chapter {
+            Some(chapter) => {
+                let Some(verses) = chapters.get(&chapter) else {
+                    return Err(BibleLibError::ChapterNotFound);
+                };
+                verses.values().collect::<Vec<&String>>()
+            }
+            None => chapters.values().flat_map(|verses| verses.values()).collect(),
+        };
+
+        let mut counts = HashMap::new();
+        for text in texts {
+            for word in search::tokenize(text) {
+                if !search::STOPWORDS.contains(&word.as_str()) {
+                    *counts.entry(word).or_insert(0) += 1;
+                }
+            }
+        }
+        let mut frequencies = counts.into_iter().collect::<Vec<(String, usize)>>();
+        frequencies.sort_by(|(a_word, a_count), (b_word, b_count)| b_count.cmp(a_count).then_with(|| a_word.cmp(b_word)));
+        Ok(frequencies)
+    }
+
+    /// Get the verses of an entire chapter as a list of (verse number, text) in order
+    /// Returns an error if the chapter is not found
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    /// // get the verses of Isaiah 53
+    /// let verses = bible.get_chapter_verses("Isaiah", 53).unwrap();
+    ///
+    /// // print each verse
+    /// for (verse, text) in verses {
+    ///     println!("{}: {}", verse, text);
+    /// }
+    /// ```
+    pub fn get_chapter_verses(&self, book: &str, chapter: u32) -> Result<Vec<(u32, String)>, BibleLibError> {
+        let Some(chapters) = self.book_chapters(book) else {
+            return Err(BibleLibError::BookNotFound);
+        };
+        let Some(verses) = chapters.get(&chapter) else {
+            return Err(BibleLibError::ChapterNotFound);
+        };
+        let mut verses = verses.iter()
+            .map(|(verse, text)| (*verse, text.to_string()))
+            .collect::<Vec<(u32, String)>>();
+        verses.sort_by_key(|(verse, _)| *verse);
+        Ok(verses)
+    }
+
+    /// Get the verses of an entire chapter as a list of (verse number, text) in order,
+    /// with the superscript verse number already prepended to each text (i.e. `¹⁶For God so loved...`)
+    /// Returns an error if the chapter is not found
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    /// // get the verses of John 3
+    /// let verses = bible.get_chapter_verses_superscript("John", 3).unwrap();
+    ///
+    /// // print each verse
+    /// for (_, text) in verses {
+    ///     println!("{}", text);
+    /// }
+    /// ```
+    pub fn get_chapter_verses_superscript(&self, book: &str, chapter: u32) -> Result<Vec<(u32, String)>, BibleLibError> {
+        Ok(self.get_chapter_verses(book, chapter)?.into_iter()
+            .map(|(verse, text)| (verse, format!("{}{}", Self::replace_superscript(verse.to_string()), text)))
+            .collect())
+    }
+
+    /// Get a list of all books in the Bible
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    ///
+    /// // get the list of books
+    /// let books = bible.get_books();
+    /// // print the list of books
+    /// println!("Books in the Bible: {:?}", books);
+    /// ```
+    pub fn get_books(&self) -> Vec<String> {
+        self.verses.keys().map(|s| s.to_string()).collect()
+    }
+
+    /// Get a list of all books in the Bible in canonical order
+    /// Books that are not part of the canon (i.e. from custom translations)
+    /// are placed after the canonical books in alphabetical order
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    ///
+    /// // get the list of books in order
+    /// let books = bible.get_books_ordered();
+    /// // print the first book
+    /// println!("First book: {}", books[0]); // genesis
+    /// ```
+    pub fn get_books_ordered(&self) -> Vec<String> {
+        let mut books = self.get_books();
+        books.sort_by(|a, b| {
+            let a_index = books::canonical_index(a).unwrap_or(usize::MAX);
+            let b_index = books::canonical_index(b).unwrap_or(usize::MAX);
+            a_index.cmp(&b_index).then_with(|| a.cmp(b))
+        });
+        books
+    }
+
+    /// Get the books of the Bible split into (Old Testament books, New Testament books), both in canonical order
+    /// Books that are not part of the canon (i.e. from custom translations) are omitted
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    ///
+    /// // get the books of each testament
+    /// let (old, new) = bible.books_by_testament();
+    /// assert_eq!(old[0], "genesis");
+    /// assert_eq!(new[0], "matthew");
+    /// ```
+    pub fn books_by_testament(&self) -> (Vec<String>, Vec<String>) {
+        let mut old = Vec::new();
+        let mut new = Vec::new();
+        for book in self.get_books_ordered() {
+            match books::testament(&book) {
+                Some(books::Testament::Old) => old.push(book),
+                Some(books::Testament::New) => new.push(book),
+                None => {}
+            }
+        }
+        (old, new)
+    }
+
+    /// Get a list of all chapters in a book
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    ///
+    /// // get the list of chapters in Revelation
+    /// let chapters = bible.get_chapters("Revelation").unwrap();
+    /// // print the list of chapters
+    /// println!("Chapters in Revelation: {:?}", chapters);
+    /// ```
+    pub fn get_chapters(&self, book: &str) -> Result<Vec<u32>, BibleLibError> {
+        // a book without chapters is treated the same as a book that does not exist
+        if let Some(chapters) = self.book_chapters(book)
+            .filter(|chapters| !chapters.is_empty())
+            .map(|chapters| chapters.keys().copied().collect()) {
+            Ok(chapters)
+        } else {
+            Err(BibleLibError::BookNotFound)
+        }
+    }
+
+    /// Get the display names of all books whose name or abbreviation starts with `prefix`
+    /// `prefix` is not case-sensitive
+    /// Books are returned in canonical order, which makes this useful for autocompletion
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    ///
+    /// // get the books starting with "jo"
+    /// let books = bible.books_with_prefix("jo");
+    /// // print the matching books
+    /// println!("Matching books: {:?}", books); // ["Joshua", "Job", "Joel", "Jonah", "John"]
+    /// ```
+    pub fn books_with_prefix(&self, prefix: &str) -> Vec<String> {
+        let prefix = prefix.to_lowercase();
+        self.get_books_ordered().into_iter()
+            .filter(|book| {
+                book.starts_with(&prefix)
+                    || books::abbreviations(book).iter().any(|abbreviation| abbreviation.starts_with(&prefix))
+            })
+            .map(|book| BibleLookup::capitalize_book(&book))
+            .collect()
+    }
+
+    /// Get the number of (books, chapters, verses) in a testament
+    /// Books that are not part of the canon (i.e. from custom translations) are not counted
+    /// Returns zeros if no books of the testament are in the Bible
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, Translation, books::Testament};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    ///
+    /// // get the stats of the New Testament
+    /// let (books, chapters, verses) = bible.testament_stats(Testament::New);
+    /// // print the stats
+    /// println!("The New Testament has {} books, {} chapters and {} verses", books, chapters, verses);
+    /// ```
+    pub fn testament_stats(&self, testament: books::Testament) -> (usize, usize, usize) {
+        self.verses.iter()
+            .filter(|(book, _)| books::testament(book) == Some(testament))
+            .fold((0, 0, 0), |(books, chapters, verses), (_, book_chapters)| {
+                let book_verses = book_chapters.values().map(|chapter| chapter.len()).sum::<usize>();
+                (books + 1, chapters + book_chapters.len(), verses + book_verses)
+            })
+    }
+
+    /// Split the Bible into two Bibles holding the (Old, New) Testaments, sharing the same translation
+    /// Each half is fully queryable on its own
+    /// Books that are not part of the canon (i.e. from custom translations) are dropped
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, BibleLookup, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    ///
+    /// // keep only the New Testament
+    /// let (_, new_testament) = bible.split_by_testament();
+    /// assert!(new_testament.get_verse(BibleLookup::new("John", 3, 16), false).is_ok());
+    /// assert!(new_testament.get_verse(BibleLookup::new("Genesis", 1, 1), false).is_err());
+    /// ```
+    pub fn split_by_testament(&self) -> (Bible, Bible) {
+        let part = |testament: books::Testament| {
+            let verses = self.verses.iter()
+                .filter(|(book, _)| books::testament(book) == Some(testament))
+                .map(|(book, chapters)| (book.clone(), chapters.clone()))
+                .collect::<VerseMap>();
+            let paragraph_starts = self.paragraph_starts.iter()
+                .filter(|(book, _, _)| verses.contains_key(book))
+                .cloned()
+                .collect::<ParagraphStarts>();
+            let mut bible = Self::from_parts(self.translation.clone(), verses, paragraph_starts);
+            bible.max_range = self.max_range;
+            bible
+        };
+        (part(books::Testament::Old), part(books::Testament::New))
+    }
+
+    /// Get the maximum chapter number in a book
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    ///
+    /// // get the last chapter of Genesis
+    /// let max_chapter = bible.get_max_chapter("Genesis").unwrap();
+    /// // print the last chapter
+    /// println!("Genesis has {} chapters", max_chapter); // 50
+    /// ```
+    pub fn get_max_chapter(&self, book: &str) -> Result<u32, BibleLibError> {
+        let Some(chapters) = self.book_chapters(book) else {
+            return Err(BibleLibError::BookNotFound);
+        };
+        chapters.keys().max().copied().ok_or(BibleLibError::BookNotFound)
+    }
+
+    /// Get every book in canonical order paired with its number of chapters
+    /// This is a lighter alternative to walking every book when only chapter totals are needed
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    ///
+    /// // get the chapter count of each book
+    /// let counts = bible.chapter_counts();
+    /// // print the chapter counts
+    /// for (book, chapters) in counts {
+    ///     println!("{}: {} chapters", book, chapters);
+    /// }
+    /// ```
+    pub fn chapter_counts(&self) -> Vec<(String, u32)> {
+        self.get_books_ordered().into_iter()
+            .filter_map(|book| {
+                let max_chapter = self.get_max_chapter(&book).ok()?;
+                Some((book, max_chapter))
+            })
+            .collect()
+    }
+
+    /// Get each chapter of a book in ascending order paired with its number of verses
+    /// Returns `BookNotFound` if the book is not found
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    ///
+    /// // get the verse count of each chapter of Jude
+    /// let counts = bible.verse_counts("Jude").unwrap();
+    /// assert_eq!(counts, vec![(1, 25)]);
+    /// ```
+    pub fn verse_counts(&self, book: &str) -> Result<Vec<(u32, u32)>, BibleLibError> {
+        let mut chapters = self.get_chapters(book)?;
+        chapters.sort();
+        let Some(book) = self.book_chapters(book) else {
+            return Err(BibleLibError::BookNotFound);
+        };
+        Ok(chapters.into_iter()
+            .map(|chapter| (chapter, book[&chapter].len() as u32))
+            .collect())
+    }
+
+    /// Get a list of all verses in a chapter of a book
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    ///
+    /// // get the list of verses in John chapter 3
+    /// let verses = bible.get_verses("John", 3).unwrap();
+    /// // print the list of verses
+    /// println!("Verses in John 3: {:?}", verses);
+    /// ```
+    pub fn get_verses(&self, book: &str, chapter: u32) -> Result<Vec<u32>, BibleLibError> {
+        if let Some(verses) = self.book_chapters(book)
+            .and_then(|chapters| chapters.get(&chapter))
+            .map(|verses| verses.keys().copied().collect()) {
+            Ok(verses)
+        } else {
+            Err(BibleLibError::ChapterNotFound)
+        }
+    }
+
+    /// Get the verse numbers between 1 and the last verse of a chapter that are missing from the translation
+    /// Verses stored without any text (how some translations mark omitted verses) count as missing
+    /// Returns `ChapterNotFound` if the chapter is not found
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::AmericanStandard).unwrap();
+    ///
+    /// // Matthew 17:21 is omitted from the American Standard Version
+    /// let gaps = bible.chapter_gaps("Matthew", 17).unwrap();
+    /// assert_eq!(gaps, vec![21]);
+    /// ```
+    pub fn chapter_gaps(&self, book: &str, chapter: u32) -> Result<Vec<u32>, BibleLibError> {
+        let Some(verses) = self.book_chapters(book)
+            .and_then(|chapters| chapters.get(&chapter)) else {
+            return Err(BibleLibError::ChapterNotFound);
+        };
+        let max_verse = verses.keys().max().copied().unwrap_or(0);
+        Ok((1..=max_verse)
+            .filter(|verse| verses.get(verse).is_none_or(|text| text.trim().is_empty()))
+            .collect())
+    }
+
+    /// Get the maximum verse number in a chapter of a book
+    pub fn get_max_verse(&self, book: &str, chapter: u32) -> Result<u32, BibleLibError> {
+        if let Some(verses) = self.book_chapters(book)
+            .and_then(|chapters| chapters.get(&chapter)) {
+            if let Some(max_verse) = verses.keys().max() {
+                Ok(*max_verse)
+            } else {
+                Err(BibleLibError::ChapterNotFound)
+            }
+        } else {
+            Err(BibleLibError::ChapterNotFound)
+        }
+    }
+
+    /// Detect Bible verses in a string, resolving references against this Bible
+    /// Requires the `detection` feature to be enabled
+    /// In addition to everything `BibleLookup::detect_from_string` finds, this also
+    /// recognizes whole chapter ranges like `Genesis 1-2`, which are returned as a range
+    /// spanning from the first verse of the first chapter to the last verse of the last chapter
+    /// Verses are returned in the order they appear in the string
+    /// Long chapter ranges (i.e. `Psalms 1-150`) may cover more verses than `get_verse` allows,
+    /// so raise the limit with `with_max_range` to get their text
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    ///
+    /// // detect verses in a reading plan
+    /// let verses = bible.detect("Today: Genesis 1-2 and John 3:16");
+    ///
+    /// // print the found verses
+    /// for verse in verses {
+    ///     println!("Found verse: {}", verse); // Genesis 1:1-2:25, John 3:16
+    /// }
+    /// ```
+    #[cfg(feature = "detection")]
+    pub fn detect<S: Into<String>>(&self, text: S) -> Vec<BibleLookup> {
+        let text = text.into();
+        let mut verses = BibleLookup::detect_spans(&text);
+
+        // detect chapter ranges (i.e. `Genesis 1-2`)
+        for instance in chapter_range_regex().find_iter(&text) {
+            // a trailing ':' means this is a verse reference, not a chapter range
+            if text[instance.end()..].starts_with(':') {
+                continue;
+            }
+            let span = instance.range();
+            let instance = instance.as_str().to_lowercase();
+            let Some((book, chapters)) = instance.rsplit_once(char::is_whitespace) else {
+                continue;
+            };
+            let book = book.split_whitespace().collect::<Vec<&str>>().join(" ");
+            let Some((chapter, thru_chapter)) = chapters.split_once('-') else {
+                continue;
+            };
+            let (Ok(chapter), Ok(thru_chapter)) = (chapter.parse::<u32>(), thru_chapter.parse::<u32>()) else {
+                continue;
+            };
+            // resolve the last verse of the last chapter
+            let Ok(thru_verse) = self.get_max_verse(&book, thru_chapter) else {
+                continue;
+            };
+            verses.push((BibleLookup::new_cross_range(book, chapter, 1, thru_chapter, thru_verse), span));
+        }
+
+        verses.sort_by_key(|(_, span)| span.start);
+        verses.into_iter().map(|(verse, _)| verse).collect()
     }
 
-    /// Get the text of a verse or range of verses
-    /// `use_superscripts` adds superscript verse numbers for better readability
-    /// Returns an error if the verse or chapter is not found
+    /// Get the first and last verses of a chapter as lookups
+    /// Returns an error if the book or chapter is not found
     /// # Example
     /// ```
-    /// use bible_lib::{Bible, BibleLookup, Translation};
+    /// use bible_lib::{Bible, Translation};
     ///
     /// // get the bible translation
-    /// let bible = Bible::new(Translation::AmericanStandard).unwrap();
-    /// // create a lookup for John 3:16
-    /// let lookup = BibleLookup::new("John", 3, 16);
-    /// // get the verse text
-    /// let verse = bible.get_verse(lookup, false).unwrap();
+    /// let bible = Bible::new(Translation::default()).unwrap();
     ///
-    /// // print the verse text
-    /// println!("John 3:16: {}", verse);
+    /// // get the bounds of John 3
+    /// let (first, last) = bible.chapter_bounds("John", 3).unwrap();
+    /// // print the bounds
+    /// println!("John 3 runs from {} to {}", first, last); // John 3:1 to John 3:36
     /// ```
-    pub fn get_verse(&self, lookup: BibleLookup, use_superscripts: bool) -> Result<String, BibleLibError> {
-        // multiple verse lookup
-        if let Some(thru_verse) = lookup.thru_verse {
-            let mut verse_text = String::new();
-
-            // iterate through the verses
-            for verse in lookup.verse..=thru_verse {
-                let Some(chapters) = self.verses.get(&lookup.book) else {
-                    return Err(BibleLibError::BookNotFound);
-                };
-                let Some(verses) = chapters.get(&lookup.chapter) else {
-                    return Err(BibleLibError::ChapterNotFound);
-                };
-                let Some(text) = verses.get(&verse) else {
-                    return Err(BibleLibError::VerseNotFound);
-                };
-
-                if use_superscripts {
-                    verse_text.push_str(&format!("{}{} ", Self::replace_superscript(verse.to_string()), text));
-                } else {
-                    verse_text.push_str(text);
-                }
-            }
-            return Ok(verse_text.trim().to_string());
-        }
-        
-        // single verse lookup
-        let Some(chapters) = self.verses.get(&lookup.book) else {
+    pub fn chapter_bounds(&self, book: &str, chapter: u32) -> Result<(BibleLookup, BibleLookup), BibleLibError> {
+        let Some(book) = self.stored_book(book).cloned() else {
             return Err(BibleLibError::BookNotFound);
         };
-        let Some(verses) = chapters.get(&lookup.chapter) else {
+        let Some(chapters) = self.verses.get(&book) else {
+            return Err(BibleLibError::BookNotFound);
+        };
+        let Some(verses) = chapters.get(&chapter) else {
             return Err(BibleLibError::ChapterNotFound);
         };
-        let Some(text) = verses.get(&lookup.verse) else {
-            return Err(BibleLibError::VerseNotFound);
+        let (Some(first), Some(last)) = (verses.keys().min(), verses.keys().max()) else {
+            return Err(BibleLibError::ChapterNotFound);
         };
+        Ok((BibleLookup::new(book.as_str(), chapter, *first), BibleLookup::new(book.as_str(), chapter, *last)))
+    }
 
-        if use_superscripts {
-            Ok(format!("{}{}", Self::replace_superscript(lookup.verse.to_string()), text))
-        } else {
-            Ok(text.to_string())
+    /// Compare which verses two Bibles contain, returning (verses in `a` but not `b`, verses in `b` but not `a`)
+    /// Each side is in canonical order, and only the presence of each verse is compared, not its text
+    /// Books are matched by their canonical name, so `psalm` in one translation matches `psalms` in another
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, parser::ParserConfig};
+    ///
+    /// let a = Bible::from_text("A", "Matthew 17:20 Because of your unbelief...\nMatthew 17:21 Howbeit this kind...", &ParserConfig::default()).unwrap();
+    /// let b = Bible::from_text("B", "Matthew 17:20 Because of your little faith...", &ParserConfig::default()).unwrap();
+    ///
+    /// // compare the verses
+    /// let (only_a, only_b) = Bible::presence_diff(&a, &b);
+    /// assert_eq!(only_a.len(), 1);
+    /// assert!(only_b.is_empty());
+    /// println!("Only in A: {}", only_a[0]); // Matthew 17:21
+    /// ```
+    pub fn presence_diff(a: &Bible, b: &Bible) -> (Vec<BibleLookup>, Vec<BibleLookup>) {
+        let missing = |from: &Bible, other: &Bible| {
+            from.ordered_verses().into_iter()
+                .map(|(lookup, _)| lookup)
+                .filter(|lookup| !other.contains_verse(lookup))
+                .collect::<Vec<BibleLookup>>()
+        };
+        (missing(a, b), missing(b, a))
+    }
+
+    /// Find the name a book is stored under, matching canonical books by their canonical name
+    /// (i.e. `psalm` matches a Bible storing `psalms`)
+    /// The book is normalized first, so lookups built as struct literals (i.e. with `book: "John"`) are found too
+    /// Every method taking a book or a `&BibleLookup` finds the book through this
+    #[doc(hidden)]
+    fn stored_book(&self, book: &str) -> Option<&String> {
+        let book = BibleLookup::normalize_book(book);
+        if let Some((stored, _)) = self.verses.get_key_value(&book) {
+            return Some(stored);
         }
+        let index = books::canonical_index(&book)?;
+        self.verses.keys().find(|stored| books::canonical_index(stored) == Some(index))
     }
 
-    /// Get the text of an entire chapter as a string
-    /// `use_superscripts` adds superscript verse numbers for better readability
-    /// Returns an error if the chapter is not found
+    /// Get the chapters of a book (see `stored_book`)
+    #[doc(hidden)]
+    fn book_chapters(&self, book: &str) -> Option<&HashMap<u32, HashMap<u32, String>>> {
+        self.stored_book(book).map(|book| &self.verses[book])
+    }
+
+    /// Find the text of a single verse (see `stored_book`)
+    #[doc(hidden)]
+    fn find_verse(&self, lookup: &BibleLookup) -> Option<&String> {
+        let book = self.stored_book(&lookup.book)?;
+        self.verses[book].get(&lookup.chapter)?.get(&lookup.verse)
+    }
+
+    /// Check if a single verse is stored (see `find_verse`)
+    #[doc(hidden)]
+    fn contains_verse(&self, lookup: &BibleLookup) -> bool {
+        self.find_verse(lookup).is_some()
+    }
+
+    /// Get every verse whose text is identical in all of the given Bibles, in canonical order
+    /// Whitespace is normalized before comparing, and verses left empty are skipped
+    /// Returns an empty list if no Bibles are given
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, Translation};
+    ///
+    /// // get the bible translations
+    /// let bibles = [
+    ///     Bible::new(Translation::KingJames).unwrap(),
+    ///     Bible::new(Translation::AmericanKingJames).unwrap(),
+    /// ];
+    ///
+    /// // find the verses the translations agree on
+    /// let identical = Bible::identical_verses(&bibles);
+    /// // print the number of identical verses
+    /// println!("{} verses are identical", identical.len());
+    /// ```
+    pub fn identical_verses(bibles: &[Bible]) -> Vec<BibleLookup> {
+        let normalize = |text: &str| text.split_whitespace().collect::<Vec<&str>>().join(" ");
+        let Some((first, rest)) = bibles.split_first() else {
+            return Vec::new();
+        };
+        first.ordered_verses().into_iter()
+            .filter(|(lookup, text)| {
+                let text = normalize(text);
+                !text.is_empty() && rest.iter().all(|bible| {
+                    bible.find_verse(lookup).is_some_and(|other| normalize(other) == text)
+                })
+            })
+            .map(|(lookup, _)| lookup)
+            .collect()
+    }
+
+    /// Get the total number of verses in the Bible
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    ///
+    /// // print the number of verses
+    /// println!("The Bible has {} verses", bible.verse_count());
+    /// ```
+    pub fn verse_count(&self) -> usize {
+        self.verses.values()
+            .flat_map(|chapters| chapters.values())
+            .map(|verses| verses.len())
+            .sum()
+    }
+
+    /// Get the verses whose absolute ordinals fall in `start..end`, in canonical order
+    /// A verse's absolute ordinal is its zero-based position among every verse of the Bible
+    /// in canonical order (Genesis 1:1 is 0), so the whole Bible can be paginated by a flat index
+    /// Ordinals past the end of the Bible are ignored
     /// # Example
     /// ```
     /// use bible_lib::{Bible, BibleLookup, Translation};
     ///
     /// // get the bible translation
-    /// let bible = Bible::new(Translation::EnglishedRevised).unwrap();
-    /// // get the text of Isaiah chapter 53
-    /// let chapter_text = bible.get_chapter("Isaiah", 53, true).unwrap();
+    /// let bible = Bible::new(Translation::default()).unwrap();
     ///
-    /// // print the chapter text
-    /// println!("Isaiah 53: {}", chapter_text);
+    /// // get the first page of 50 verses
+    /// let page = bible.verses_in_index_range(0, 50);
+    /// assert_eq!(page.len(), 50);
+    /// assert_eq!(page[0].0, BibleLookup::new("genesis", 1, 1));
+    /// // print the page
+    /// for (verse, text) in page {
+    ///     println!("{}: {}", verse, text);
+    /// }
     /// ```
-    pub fn get_chapter(&self, book: &str, chapter: u32, use_superscripts: bool) -> Result<String, BibleLibError> {
-        let mut chapter_text = String::new();
-        // sort the verses by verse number
-        let Some(chapters) = self.verses.get(book) else {
-            return Err(BibleLibError::BookNotFound);
-        };
-        let Some(verses) = chapters.get(&chapter) else {
-            return Err(BibleLibError::ChapterNotFound);
+    pub fn verses_in_index_range(&self, start: usize, end: usize) -> Vec<(BibleLookup, String)> {
+        if start >= end {
+            return Vec::new();
+        }
+        self.ordered_verses().into_iter()
+            .skip(start)
+            .take(end - start)
+            .map(|(lookup, text)| (lookup, text.to_string()))
+            .collect()
+    }
+
+    /// Expand a lookup into single verse lookups for every verse it covers that exists in the Bible
+    /// Verses are returned in order; verses missing from the translation are skipped
+    #[doc(hidden)]
+    fn expand_existing(&self, lookup: &BibleLookup) -> Vec<BibleLookup> {
+        let Some(book) = self.stored_book(&lookup.book) else {
+            return Vec::new();
         };
-        let mut verses = verses.iter().collect::<Vec<(&u32, &String)>>();
-        verses.sort_by(|a, b| a.0.cmp(b.0));
-        for (verse, text) in verses {
-            let verse_designation = Self::replace_superscript(verse.to_string());
-            if use_superscripts {
-                chapter_text.push_str(&format!("{}{} ", verse_designation, text));
+        let chapters = &self.verses[book];
+        let (start, end) = lookup.bounds();
+
+        let mut chapter_numbers = chapters.keys().copied()
+            .filter(|chapter| (start.0..=end.0).contains(chapter))
+            .collect::<Vec<u32>>();
+        chapter_numbers.sort();
+
+        let mut expanded = Vec::new();
+        for chapter in chapter_numbers {
+            let mut verse_numbers = chapters[&chapter].keys().copied()
+                .filter(|verse| start <= (chapter, *verse) && (chapter, *verse) <= end)
+                .collect::<Vec<u32>>();
+            verse_numbers.sort();
+            expanded.extend(verse_numbers.into_iter().map(|verse| BibleLookup::new(book.as_str(), chapter, verse)));
+        }
+        expanded
+    }
+
+    /// Get the verse following a lookup in canonical order, continuing into the next chapter or book as needed
+    /// For ranges, this is the verse following the end of the range
+    /// Returns `None` if the lookup is the last verse of the Bible or is not found
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, BibleLookup, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    ///
+    /// // get the verse after John 3:36
+    /// let next = bible.next_verse(&BibleLookup::new("John", 3, 36)).unwrap();
+    /// assert_eq!(next, BibleLookup::new("John", 4, 1));
+    ///
+    /// // John 3 only has 36 verses, so John 3:99 has no next verse
+    /// assert_eq!(bible.next_verse(&BibleLookup::new("John", 3, 99)), None);
+    /// ```
+    pub fn next_verse(&self, lookup: &BibleLookup) -> Option<BibleLookup> {
+        let (_, (chapter, verse)) = lookup.bounds();
+        let book = self.stored_book(&lookup.book)?;
+        // a missing verse has no neighbours, even if a later verse of the chapter exists
+        if !self.contains_verse(&BibleLookup::new(book.as_str(), chapter, verse)) {
+            return None;
+        }
+        let chapters = &self.verses[book];
+
+        // the next verse in the same chapter
+        if let Some(next) = chapters[&chapter].keys().copied().filter(|v| *v > verse).min() {
+            return Some(BibleLookup::new(book.as_str(), chapter, next));
+        }
+        // the first verse of the next chapter
+        if let Some(next_chapter) = chapters.keys().copied().filter(|c| *c > chapter).min() {
+            let first = chapters[&next_chapter].keys().min()?;
+            return Some(BibleLookup::new(book.as_str(), next_chapter, *first));
+        }
+        // the first verse of the next book
+        let books = self.get_books_ordered();
+        let index = books.iter().position(|other| other == book)?;
+        let next_book = books.get(index + 1)?;
+        let (first, _) = self.chapter_bounds(next_book, *self.verses[next_book].keys().min()?).ok()?;
+        Some(first)
+    }
+
+    /// Get the verse preceding a lookup in canonical order, continuing into the previous chapter or book as needed
+    /// For ranges, this is the verse preceding the start of the range
+    /// Returns `None` if the lookup is the first verse of the Bible or is not found
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, BibleLookup, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    ///
+    /// // get the verse before John 4:1
+    /// let previous = bible.previous_verse(&BibleLookup::new("John", 4, 1)).unwrap();
+    /// assert_eq!(previous, BibleLookup::new("John", 3, 36));
+    ///
+    /// // the King James Version names the book `psalms`, but `psalm` still finds it
+    /// let kjv = Bible::new(Translation::KingJames).unwrap();
+    /// let previous = kjv.previous_verse(&BibleLookup::new("Psalm", 23, 2)).unwrap();
+    /// assert_eq!(previous, BibleLookup::new("Psalms", 23, 1));
+    /// ```
+    pub fn previous_verse(&self, lookup: &BibleLookup) -> Option<BibleLookup> {
+        let ((chapter, verse), _) = lookup.bounds();
+        let book = self.stored_book(&lookup.book)?;
+        // a missing verse has no neighbours, even if an earlier verse of the chapter exists
+        if !self.contains_verse(&BibleLookup::new(book.as_str(), chapter, verse)) {
+            return None;
+        }
+        let chapters = &self.verses[book];
+
+        // the previous verse in the same chapter
+        if let Some(previous) = chapters[&chapter].keys().copied().filter(|v| *v < verse).max() {
+            return Some(BibleLookup::new(book.as_str(), chapter, previous));
+        }
+        // the last verse of the previous chapter
+        if let Some(previous_chapter) = chapters.keys().copied().filter(|c| *c < chapter).max() {
+            let last = chapters[&previous_chapter].keys().max()?;
+            return Some(BibleLookup::new(book.as_str(), previous_chapter, *last));
+        }
+        // the last verse of the previous book
+        let books = self.get_books_ordered();
+        let index = books.iter().position(|other| other == book)?;
+        let previous_book = books.get(index.checked_sub(1)?)?;
+        let (_, last) = self.chapter_bounds(previous_book, *self.verses[previous_book].keys().max()?).ok()?;
+        Some(last)
+    }
+
+    /// Get the verse `delta` verses away from a lookup in canonical order, crossing chapters and books as needed
+    /// A positive `delta` moves forward from the end of the lookup, and a negative `delta` moves backward from its start
+    /// (a `delta` of 0 returns the lookup itself)
+    /// Returns `None` if the lookup is not found or the offset runs off either end of the Bible
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, BibleLookup, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    ///
+    /// // jump 3 verses forward from John 3:35
+    /// let verse = bible.offset_verse(&BibleLookup::new("John", 3, 35), 3).unwrap();
+    /// assert_eq!(verse, BibleLookup::new("John", 4, 2));
+    /// // and 3 verses back
+    /// let verse = bible.offset_verse(&verse, -3).unwrap();
+    /// assert_eq!(verse, BibleLookup::new("John", 3, 35));
+    ///
+    /// // John 3 only has 36 verses, so John 3:99 has no neighbours
+    /// assert_eq!(bible.offset_verse(&BibleLookup::new("John", 3, 99), 1), None);
+    /// ```
+    pub fn offset_verse(&self, lookup: &BibleLookup, delta: i64) -> Option<BibleLookup> {
+        // a missing lookup has no neighbours, even if a verse exists `delta` verses past its number
+        if self.expand_existing(lookup).is_empty() {
+            return None;
+        }
+        let mut current = lookup.clone();
+        for _ in 0..delta.unsigned_abs() {
+            current = if delta > 0 {
+                self.next_verse(&current)?
             } else {
-                chapter_text.push_str(&format!("{} ", text));
-            }
+                self.previous_verse(&current)?
+            };
         }
-        Ok(chapter_text)
+        Some(current)
     }
 
-    /// Get a list of all books in the Bible
+    /// Convert a reference from one verse numbering to another
+    /// (i.e. Psalm titles are counted as verses in the Hebrew numbering but not in English translations)
+    /// Ranges have both their start and end converted
     /// # Example
     /// ```
-    /// use bible_lib::{Bible, Translation};
+    /// use bible_lib::{Bible, BibleLookup, Translation, mapping::VerseMapping};
     ///
     /// // get the bible translation
     /// let bible = Bible::new(Translation::default()).unwrap();
     ///
-    /// // get the list of books
-    /// let books = bible.get_books();
-    /// // print the list of books
-    /// println!("Books in the Bible: {:?}", books);
+    /// // Malachi 3:19 in Hebrew numbering is Malachi 4:1 in English translations
+    /// let lookup = bible.map_reference(&BibleLookup::new("Malachi", 3, 19), &VerseMapping::Hebrew, &VerseMapping::English);
+    /// assert_eq!(lookup, BibleLookup::new("Malachi", 4, 1));
+    /// // print the verse
+    /// println!("{}: {}", lookup, bible.get_verse(lookup.clone(), false).unwrap());
     /// ```
-    pub fn get_books(&self) -> Vec<String> {
-        self.verses.keys().map(|s| s.to_string()).collect()
+    pub fn map_reference(&self, lookup: &BibleLookup, from: &VerseMapping, to: &VerseMapping) -> BibleLookup {
+        let convert = |(chapter, verse): (u32, u32)| {
+            let (chapter, verse) = from.to_english(&lookup.book, chapter, verse);
+            to.english_to(&lookup.book, chapter, verse)
+        };
+        let (start, end) = lookup.bounds();
+        let (chapter, verse) = convert(start);
+        if lookup.thru_verse.is_none() {
+            return BibleLookup::new(lookup.book.as_str(), chapter, verse);
+        }
+        let (thru_chapter, thru_verse) = convert(end);
+        BibleLookup::new_cross_range(lookup.book.as_str(), chapter, verse, thru_chapter, thru_verse)
     }
 
-    /// Get a list of all chapters in a book
+    /// Get the previous and next verses of a lookup as path segments (i.e. `john/3/15` and `john/3/17`),
+    /// ready to be used as navigation links
+    /// Either is `None` at the start or end of the Bible
     /// # Example
     /// ```
-    /// use bible_lib::{Bible, Translation};
+    /// use bible_lib::{Bible, BibleLookup, Translation};
     ///
     /// // get the bible translation
     /// let bible = Bible::new(Translation::default()).unwrap();
     ///
-    /// // get the list of chapters in Revelation
-    /// let chapters = bible.get_chapters("Revelation").unwrap();
-    /// // print the list of chapters
-    /// println!("Chapters in Revelation: {:?}", chapters);
+    /// // get the links around John 3:16
+    /// let (previous, next) = bible.adjacent_refs(&BibleLookup::new("John", 3, 16));
+    /// assert_eq!(previous, Some("john/3/15".to_string()));
+    /// assert_eq!(next, Some("john/3/17".to_string()));
     /// ```
-    pub fn get_chapters(&self, book: &str) -> Result<Vec<u32>, BibleLibError> {
-        if let Some(chapters) = self.verses.get(book).map(|chapters| chapters.keys().map(|c| *c).collect()) {
-            Ok(chapters)
-        } else {
-            Err(BibleLibError::BookNotFound)
+    pub fn adjacent_refs(&self, lookup: &BibleLookup) -> (Option<String>, Option<String>) {
+        let previous = self.previous_verse(lookup).map(|verse| verse.to_path_segment());
+        let next = self.next_verse(lookup).map(|verse| verse.to_path_segment());
+        (previous, next)
+    }
+
+    /// Get a verse (or range) along with up to `radius` verses before and after it, in canonical order
+    /// The window crosses chapter and book boundaries as needed, and is clamped at the start and end of the Bible
+    /// Returns an empty list if the lookup is not found
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, BibleLookup, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    ///
+    /// // get the two verses on either side of John 4:1
+    /// let window = bible.reading_window(&BibleLookup::new("John", 4, 1), 2);
+    /// assert_eq!(window.len(), 5);
+    /// assert_eq!(window[0].0, BibleLookup::new("John", 3, 35));
+    /// // print the window
+    /// for (verse, text) in window {
+    ///     println!("{}: {}", verse, text);
+    /// }
+    /// ```
+    pub fn reading_window(&self, lookup: &BibleLookup, radius: u32) -> Vec<(BibleLookup, String)> {
+        let target = self.expand_existing(lookup);
+        let (Some(first), Some(last)) = (target.first().cloned(), target.last().cloned()) else {
+            return Vec::new();
+        };
+
+        let mut before = Vec::new();
+        let mut current = first;
+        for _ in 0..radius {
+            let Some(previous) = self.previous_verse(&current) else {
+                break;
+            };
+            before.push(previous.clone());
+            current = previous;
+        }
+        before.reverse();
+
+        let mut after = Vec::new();
+        let mut current = last;
+        for _ in 0..radius {
+            let Some(next) = self.next_verse(&current) else {
+                break;
+            };
+            after.push(next.clone());
+            current = next;
         }
+
+        before.into_iter().chain(target).chain(after)
+            .map(|verse| {
+                let text = self.verses[&verse.book][&verse.chapter][&verse.verse].clone();
+                (verse, text)
+            })
+            .collect()
     }
 
-    /// Get a list of all verses in a chapter of a book
+    /// Get the percentage (0.0 to 100.0) of the Bible's verses covered by the `read` lookups
+    /// Ranges are expanded, and verses covered more than once are only counted once
     /// # Example
     /// ```
-    /// use bible_lib::{Bible, Translation};
+    /// use bible_lib::{Bible, BibleLookup, Translation};
     ///
     /// // get the bible translation
     /// let bible = Bible::new(Translation::default()).unwrap();
     ///
-    /// // get the list of verses in John chapter 3
-    /// let verses = bible.get_verses("John", 3).unwrap();
-    /// // print the list of verses
-    /// println!("Verses in John 3: {:?}", verses);
+    /// // the verses that have been read
+    /// let read = vec![
+    ///     BibleLookup::new_range("John", 3, 1, 21),
+    ///     BibleLookup::new("John", 3, 16),
+    /// ];
+    /// // print the progress
+    /// println!("Progress: {:.4}%", bible.read_progress(&read));
     /// ```
-    pub fn get_verses(&self, book: &str, chapter: u32) -> Result<Vec<u32>, BibleLibError> {
-        if let Some(verses) = self.verses.get(book)
-            .and_then(|chapters| chapters.get(&chapter))
-            .map(|verses| verses.keys().map(|v| *v).collect()) {
-            Ok(verses)
-        } else {
-            Err(BibleLibError::ChapterNotFound)
+    pub fn read_progress(&self, read: &[BibleLookup]) -> f64 {
+        let total = self.verse_count();
+        if total == 0 {
+            return 0.0;
         }
+        let covered = read.iter()
+            .flat_map(|lookup| self.expand_existing(lookup))
+            .collect::<HashSet<BibleLookup>>();
+        covered.len() as f64 / total as f64 * 100.0
     }
 
-    /// Get the maximum verse number in a chapter of a book
-    pub fn get_max_verse(&self, book: &str, chapter: u32) -> Result<u32, BibleLibError> {
-        if let Some(verses) = self.verses.get(book)
-            .and_then(|chapters| chapters.get(&chapter)) {
-            if let Some(max_verse) = verses.keys().max() {
-                Ok(*max_verse)
-            } else {
-                Err(BibleLibError::ChapterNotFound)
+    /// Get the verses whose text references `target` (i.e. cross-references in annotated translations)
+    /// Requires the `detection` feature to be enabled
+    /// A verse counts if any reference detected in its text overlaps `target`
+    /// Results are in canonical order
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, BibleLookup, parser::ParserConfig};
+    ///
+    /// let text = "Romans 1:17 For therein is the righteousness of God revealed (see Habakkuk 2:4)\nHabakkuk 2:4 ...the just shall live by his faith.";
+    /// let bible = Bible::from_text("Annotated", text, &ParserConfig::default()).unwrap();
+    ///
+    /// // find the verses referencing Habakkuk 2:4
+    /// let verses = bible.verses_referencing(&BibleLookup::new("Habakkuk", 2, 4));
+    /// assert_eq!(verses, vec![BibleLookup::new("Romans", 1, 17)]);
+    /// ```
+    #[cfg(feature = "detection")]
+    pub fn verses_referencing(&self, target: &BibleLookup) -> Vec<BibleLookup> {
+        self.ordered_verses().into_iter()
+            .filter(|(_, text)| {
+                BibleLookup::detect_from_string(text.as_str()).iter().any(|reference| reference.overlaps(target))
+            })
+            .map(|(lookup, _)| lookup)
+            .collect()
+    }
+
+    /// Load a translation, detect the Bible verses in a string and get the text of each one
+    /// Requires the `detection` feature to be enabled
+    /// This is a one-shot convenience for `Bible::new` followed by `detect` and `get_verse`
+    /// Errors loading the translation are returned; detected verses not found in the translation are skipped
+    /// Detected ranges are limited to `DEFAULT_MAX_RANGE` verses like any other, so the string can't request huge spans of text:
+    /// a longer range (i.e. `Psalms 1-150`) returns `RangeTooLong`
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, Translation, error::BibleLibError};
+    ///
+    /// // detect and render the verses in the string
+    /// let verses = Bible::scan_and_render("Show me John 3:16", Translation::default(), false).unwrap();
+    ///
+    /// // print the verses
+    /// for (verse, text) in verses {
+    ///     println!("{}: {}", verse, text);
+    /// }
+    ///
+    /// // whole books are too long to render
+    /// let result = Bible::scan_and_render("Read Genesis 1-50", Translation::default(), false);
+    /// assert!(matches!(result, Err(BibleLibError::RangeTooLong(Bible::DEFAULT_MAX_RANGE))));
+    /// ```
+    #[cfg(feature = "detection")]
+    pub fn scan_and_render(text: &str, translation: Translation, use_superscripts: bool) -> Result<Vec<(BibleLookup, String)>, BibleLibError> {
+        let bible = Self::new(translation)?;
+        let mut verses = Vec::new();
+        for verse in bible.detect(text) {
+            match bible.get_verse(verse.clone(), use_superscripts) {
+                Ok(verse_text) => verses.push((verse, verse_text)),
+                // report ranges that are too long instead of silently dropping them
+                Err(BibleLibError::RangeTooLong(max)) => return Err(BibleLibError::RangeTooLong(max)),
+                Err(_) => {}
             }
-        } else {
-            Err(BibleLibError::ChapterNotFound)
         }
+        Ok(verses)
     }
 
     /// Get a random verse from the Bible
@@ -606,7 +3350,36 @@ impl Bible {
             chapter,
             verse,
             thru_verse: None,
+            thru_chapter: None,
         }
     }
 
+    /// Get every verse of the Bible in a shuffled order determined by `seed`
+    /// Requires the `random` feature to be enabled
+    /// The same seed always produces the same order (for the same version of the crate),
+    /// and unlike repeated calls to `random_verse`, every verse appears exactly once
+    /// # Example
+    /// ```
+    /// use bible_lib::{Bible, Translation};
+    ///
+    /// // get the bible translation
+    /// let bible = Bible::new(Translation::default()).unwrap();
+    ///
+    /// // shuffle the verses
+    /// let verses = bible.shuffled_verses(42);
+    /// assert_eq!(verses, bible.shuffled_verses(42));
+    /// // print the first verse of the shuffled order
+    /// println!("First verse: {}", verses[0]);
+    /// ```
+    #[cfg(feature = "random")]
+    pub fn shuffled_verses(&self, seed: u64) -> Vec<BibleLookup> {
+        use rand::{SeedableRng, rngs::StdRng, seq::SliceRandom};
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut verses = self.ordered_verses().into_iter()
+            .map(|(lookup, _)| lookup)
+            .collect::<Vec<BibleLookup>>();
+        verses.shuffle(&mut rng);
+        verses
+    }
+
 }
\ No newline at end of file
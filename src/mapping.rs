@@ -0,0 +1,97 @@
+use crate::books;
+
+/// Verse numbering schemes used by different translations
+/// References are converted between schemes with `Bible::map_reference`
+/// # Example
+/// ```
+/// use bible_lib::{Bible, BibleLookup, Translation, mapping::VerseMapping};
+///
+/// // get the bible translation
+/// let bible = Bible::new(Translation::default()).unwrap();
+///
+/// // Psalm 51:1 in English numbering is Psalm 51:3 in Hebrew numbering
+/// let lookup = bible.map_reference(&BibleLookup::new("Psalms", 51, 1), &VerseMapping::English, &VerseMapping::Hebrew);
+/// assert_eq!(lookup, BibleLookup::new("Psalms", 51, 3));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VerseMapping {
+    /// The numbering used by English translations such as the King James Version
+    English,
+    /// The numbering of the Hebrew (Masoretic) text, where:
+    /// - Psalm titles are counted as the first verse (or two) of the psalm
+    /// - Malachi 4 is Malachi 3:19-24
+    /// - Joel 2:28-32 is Joel 3, and Joel 3 is Joel 4
+    Hebrew,
+}
+
+/// Psalms whose titles are counted as verses in the Hebrew numbering,
+/// with the number of verses the title takes up
+const PSALM_TITLE_VERSES: &[(u32, u32)] = &[
+    (3, 1), (4, 1), (5, 1), (6, 1), (7, 1), (8, 1), (9, 1), (12, 1), (13, 1), (18, 1),
+    (19, 1), (20, 1), (21, 1), (22, 1), (30, 1), (31, 1), (34, 1), (36, 1), (38, 1), (39, 1),
+    (40, 1), (41, 1), (42, 1), (44, 1), (45, 1), (46, 1), (47, 1), (48, 1), (49, 1), (51, 2),
+    (52, 2), (53, 1), (54, 2), (55, 1), (56, 1), (57, 1), (58, 1), (59, 1), (60, 2), (61, 1),
+    (62, 1), (63, 1), (64, 1), (65, 1), (67, 1), (68, 1), (69, 1), (70, 1), (75, 1), (76, 1),
+    (77, 1), (80, 1), (81, 1), (83, 1), (84, 1), (85, 1), (88, 1), (89, 1), (92, 1), (102, 1),
+    (108, 1), (140, 1), (142, 1),
+];
+
+impl VerseMapping {
+    /// Convert a (chapter, verse) in this numbering to the English numbering
+    #[doc(hidden)]
+    pub(crate) fn to_english(self, book: &str, chapter: u32, verse: u32) -> (u32, u32) {
+        match self {
+            Self::English => (chapter, verse),
+            Self::Hebrew => {
+                if is_book(book, "psalms") {
+                    // the title has no verse of its own in English, so it maps to the first verse
+                    let shift = psalm_title_verses(chapter);
+                    (chapter, verse.saturating_sub(shift).max(1))
+                } else if is_book(book, "malachi") && chapter == 3 && verse >= 19 {
+                    (4, verse - 18)
+                } else if is_book(book, "joel") && chapter == 3 {
+                    (2, verse + 27)
+                } else if is_book(book, "joel") && chapter == 4 {
+                    (3, verse)
+                } else {
+                    (chapter, verse)
+                }
+            }
+        }
+    }
+
+    /// Convert a (chapter, verse) in the English numbering to this numbering
+    #[doc(hidden)]
+    pub(crate) fn english_to(self, book: &str, chapter: u32, verse: u32) -> (u32, u32) {
+        match self {
+            Self::English => (chapter, verse),
+            Self::Hebrew => {
+                if is_book(book, "psalms") {
+                    (chapter, verse + psalm_title_verses(chapter))
+                } else if is_book(book, "malachi") && chapter == 4 {
+                    (3, verse + 18)
+                } else if is_book(book, "joel") && chapter == 2 && verse >= 28 {
+                    (3, verse - 27)
+                } else if is_book(book, "joel") && chapter == 3 {
+                    (4, verse)
+                } else {
+                    (chapter, verse)
+                }
+            }
+        }
+    }
+}
+
+/// Check if a book is the given canonical book (i.e. `psalm` is `psalms`)
+#[doc(hidden)]
+fn is_book(book: &str, canonical: &str) -> bool {
+    books::canonical_index(book).is_some_and(|index| Some(index) == books::canonical_index(canonical))
+}
+
+/// Get the number of verses a psalm's title takes up in the Hebrew numbering
+#[doc(hidden)]
+fn psalm_title_verses(psalm: u32) -> u32 {
+    PSALM_TITLE_VERSES.iter()
+        .find(|(number, _)| *number == psalm)
+        .map_or(0, |(_, verses)| *verses)
+}
@@ -0,0 +1,84 @@
+/// Configuration for parsing the text of a translation
+/// The default matches the format of the bundled translations:
+/// `Book Chapter:Verse Text`, with whitespace between the reference and the text
+/// # Example
+/// ```
+/// use bible_lib::parser::ParserConfig;
+///
+/// // parse files formatted as `John 3:16\tFor God so loved...`
+/// let config = ParserConfig {
+///     separator: Some("\t".to_string()),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParserConfig {
+    /// The string separating the reference from the verse text (i.e. a tab or `|`)
+    /// `None` uses the whitespace following the verse number
+    pub separator: Option<String>,
+    /// Fold curly quotes and apostrophes into straight ones (see `normalize_text`)
+    /// Disabled by default so translations that intentionally use typographic quotes are left unchanged
+    pub normalize_quotes: bool,
+    /// Remove a reference accidentally repeated at the start of the verse text (see `strip_leading_reference`)
+    pub strip_leading_reference: bool,
+}
+
+/// Fold curly quotes (`“ ” ‘ ’` and similar) into straight quotes (`"` and `'`)
+/// Makes searching for words like `don't` work across sources
+/// # Example
+/// ```
+/// use bible_lib::parser::normalize_text;
+///
+/// assert_eq!(normalize_text("“Don’t be afraid”"), "\"Don't be afraid\"");
+/// ```
+pub fn normalize_text(text: &str) -> String {
+    text.chars().map(|c| {
+        match c {
+            '“' | '”' | '„' | '‟' => '"',
+            '‘' | '’' | '‚' | '‛' => '\'',
+            _ => c,
+        }
+    }).collect()
+}
+
+/// Remove a leading `Book Chapter:Verse` reference repeated at the start of a verse's text
+/// (i.e. `John 3:16 For God so loved...` becomes `For God so loved...`), a common data-entry artifact
+/// This is conservative: the text is only changed if it starts with the verse's own reference,
+/// `book` (not case-sensitive) followed by exactly `chapter:verse`
+/// # Example
+/// ```
+/// use bible_lib::parser::strip_leading_reference;
+///
+/// assert_eq!(strip_leading_reference("John", 3, 16, "John 3:16 For God so loved..."), "For God so loved...");
+/// // text that only mentions the book is left alone
+/// assert_eq!(strip_leading_reference("John", 1, 15, "John bare record..."), "John bare record...");
+/// // as is a reference to a different verse
+/// assert_eq!(strip_leading_reference("John", 3, 17, "John 3:16 For God so loved..."), "John 3:16 For God so loved...");
+/// ```
+pub fn strip_leading_reference(book: &str, chapter: u32, verse: u32, text: &str) -> String {
+    let trimmed = text.trim_start();
+    let Some(prefix) = trimmed.get(..book.len()) else {
+        return text.to_string();
+    };
+    if prefix.to_lowercase() != book.to_lowercase() {
+        return text.to_string();
+    }
+
+    // the book must be followed by whitespace and a `chapter:verse` reference
+    let rest = &trimmed[book.len()..];
+    if !rest.starts_with(char::is_whitespace) {
+        return text.to_string();
+    }
+    let rest = rest.trim_start();
+    let (reference, remainder) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+    let Some((reference_chapter, reference_verse)) = reference.split_once(':') else {
+        return text.to_string();
+    };
+    // only the verse's own reference is an artifact; any other reference may be part of the text
+    let is_reference = |n: &str, expected: u32| n.chars().all(|c| c.is_ascii_digit()) && n.parse::<u32>() == Ok(expected);
+    if !is_reference(reference_chapter, chapter) || !is_reference(reference_verse, verse) {
+        return text.to_string();
+    }
+
+    remainder.trim_start().to_string()
+}
@@ -0,0 +1,134 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::{BibleLookup, books, error::BibleLibError};
+
+/// A passage running from one verse to another, which (unlike a `BibleLookup`) may span books
+/// Get its text with `Bible::get_passage`
+/// # Example
+/// ```
+/// use bible_lib::{Bible, BibleLookup, Translation, passage::Passage};
+///
+/// // get the bible translation
+/// let bible = Bible::new(Translation::default()).unwrap();
+///
+/// // the end of Genesis and the start of Exodus
+/// let passage: Passage = "Genesis 50:26 to Exodus 1:1".parse().unwrap();
+/// assert_eq!(passage.start, BibleLookup::new("Genesis", 50, 26));
+/// assert_eq!(passage.end, BibleLookup::new("Exodus", 1, 1));
+///
+/// // print the passage
+/// println!("{}: {}", passage, bible.get_passage(&passage, false).unwrap());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Passage {
+    /// The first verse of the passage
+    pub start: BibleLookup,
+    /// The last verse of the passage
+    pub end: BibleLookup,
+}
+
+impl Passage {
+    /// Create a new Passage from its first and last verse
+    /// If either lookup is a range, the passage starts at the start of `start` and ends at the end of `end`
+    /// # Example
+    /// ```
+    /// use bible_lib::{BibleLookup, passage::Passage};
+    ///
+    /// // create a passage for Matthew 5:1 to 7:29 (the Sermon on the Mount)
+    /// let passage = Passage::new(BibleLookup::new("Matthew", 5, 1), BibleLookup::new("Matthew", 7, 29));
+    /// ```
+    pub fn new(start: BibleLookup, end: BibleLookup) -> Self {
+        let ((chapter, verse), _) = start.bounds();
+        let (_, (thru_chapter, thru_verse)) = end.bounds();
+        Self {
+            start: BibleLookup::new(start.book.as_str(), chapter, verse),
+            end: BibleLookup::new(end.book.as_str(), thru_chapter, thru_verse),
+        }
+    }
+
+    /// Get the passage as a single lookup, if it stays within one book
+    /// # Example
+    /// ```
+    /// use bible_lib::{BibleLookup, passage::Passage};
+    ///
+    /// let passage: Passage = "Matthew 5:1 to Matthew 7:29".parse().unwrap();
+    /// assert_eq!(passage.to_lookup(), Some(BibleLookup::new_cross_range("Matthew", 5, 1, 7, 29)));
+    /// ```
+    pub fn to_lookup(&self) -> Option<BibleLookup> {
+        if self.start.book != self.end.book {
+            return None;
+        }
+        if self.start == self.end {
+            return Some(self.start.clone());
+        }
+        Some(BibleLookup::new_cross_range(self.start.book.as_str(), self.start.chapter, self.start.verse, self.end.chapter, self.end.verse))
+    }
+}
+
+/// Split a passage written as two full references (i.e. `Matthew 5:1 to Matthew 7:29`)
+/// at the word `to`, or a hyphen, en dash or em dash followed by a book name
+/// Returns `None` if `s` is not written as two references
+#[doc(hidden)]
+pub(crate) fn split_passage(s: &str) -> Option<(String, String)> {
+    // the word "to", surrounded by any whitespace
+    let words = s.split_whitespace().collect::<Vec<&str>>();
+    if let Some(index) = words.iter().position(|word| word.eq_ignore_ascii_case("to")) {
+        return Some((words[..index].join(" "), words[index + 1..].join(" ")));
+    }
+    // a dash or hyphen is only a separator if a book name follows it (`John 3:16-18` is a verse range)
+    let (start, end) = s.split_once(['-', '–', '—'])?;
+    let end = end.trim();
+    end.chars().any(char::is_alphabetic).then(|| (start.trim().to_string(), end.to_string()))
+}
+
+impl Display for Passage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.to_lookup() {
+            Some(lookup) => write!(f, "{}", lookup),
+            None => write!(f, "{}–{}", self.start, self.end),
+        }
+    }
+}
+
+/// Parse a Passage from a string
+/// Accepts two full references joined by `to` or a dash (i.e. `Matthew 5:1 to Matthew 7:29`
+/// or `Genesis 50:26–Exodus 1:1`), as well as a single reference or range (i.e. `John 3:16-18`)
+/// Returns `InvalidVerseFormat` if either reference is malformed, or a canonical passage ends before it starts
+/// # Example
+/// ```
+/// use bible_lib::{BibleLookup, passage::Passage};
+///
+/// // parse a passage spanning books
+/// let passage: Passage = "Genesis 50:26–Exodus 1:1".parse().unwrap();
+/// assert_eq!(passage.end, BibleLookup::new("Exodus", 1, 1));
+///
+/// // parse a single range
+/// let passage: Passage = "John 3:16-18".parse().unwrap();
+/// assert_eq!(passage.end, BibleLookup::new("John", 3, 18));
+/// // a dash before a verse number is a range too
+/// let passage: Passage = "John 3:16–18".parse().unwrap();
+/// assert_eq!(passage.end, BibleLookup::new("John", 3, 18));
+/// ```
+impl FromStr for Passage {
+    type Err = BibleLibError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let passage = match split_passage(s) {
+            Some((start, end)) => Self::new(start.parse()?, end.parse()?),
+            None => {
+                let lookup = s.parse::<BibleLookup>()?;
+                Self::new(lookup.clone(), lookup)
+            }
+        };
+
+        // make sure canonical passages don't run backwards
+        let position = |lookup: &BibleLookup| {
+            books::canonical_index(&lookup.book).map(|index| (index, lookup.chapter, lookup.verse))
+        };
+        if let (Some(start), Some(end)) = (position(&passage.start), position(&passage.end))
+            && start > end {
+            return Err(BibleLibError::InvalidVerseFormat);
+        }
+        Ok(passage)
+    }
+}
@@ -0,0 +1,109 @@
+use crate::{BibleLookup, books};
+
+/// A few hundred commonly cited verses as (book, chapter, verse), from most to least well known
+/// The order is an approximation based on how often each verse is quoted and shared:
+/// the first 150 are ranked individually, and the rest are ranked below them, grouped by book
+pub const POPULAR_VERSES: &[(&str, u32, u32)] = &[
+    ("john", 3, 16), ("jeremiah", 29, 11), ("philippians", 4, 13), ("romans", 8, 28), ("genesis", 1, 1),
+    ("proverbs", 3, 5), ("proverbs", 3, 6), ("romans", 12, 2), ("philippians", 4, 6), ("matthew", 28, 19),
+    ("ephesians", 2, 8), ("galatians", 5, 22), ("romans", 3, 23), ("isaiah", 41, 10), ("john", 14, 6),
+    ("2 timothy", 3, 16), ("romans", 6, 23), ("matthew", 11, 28), ("joshua", 1, 9), ("romans", 10, 9),
+    ("psalms", 23, 1), ("1 corinthians", 13, 4), ("hebrews", 11, 1), ("romans", 5, 8), ("galatians", 2, 20),
+    ("2 corinthians", 5, 17), ("isaiah", 40, 31), ("philippians", 4, 7), ("matthew", 6, 33), ("1 peter", 5, 7),
+    ("romans", 12, 1), ("john", 1, 1), ("1 john", 1, 9), ("ephesians", 2, 9), ("matthew", 28, 20),
+    ("acts", 1, 8), ("proverbs", 22, 6), ("romans", 15, 13), ("psalms", 46, 10), ("hebrews", 12, 2),
+    ("john", 10, 10), ("2 corinthians", 12, 9), ("matthew", 5, 16), ("micah", 6, 8), ("matthew", 22, 37),
+    ("romans", 8, 38), ("romans", 8, 39), ("ephesians", 6, 12), ("1 corinthians", 10, 13), ("psalms", 119, 105),
+    ("john", 11, 25), ("colossians", 3, 23), ("james", 1, 2), ("hebrews", 4, 12), ("john", 16, 33),
+    ("isaiah", 53, 5), ("matthew", 5, 14), ("2 chronicles", 7, 14), ("deuteronomy", 31, 6), ("psalms", 37, 4),
+    ("lamentations", 3, 22), ("lamentations", 3, 23), ("ephesians", 4, 32), ("romans", 8, 1), ("john", 15, 13),
+    ("james", 1, 5), ("1 thessalonians", 5, 18), ("psalms", 27, 1), ("matthew", 7, 7), ("isaiah", 26, 3),
+    ("romans", 1, 16), ("john", 14, 27), ("psalms", 34, 8), ("2 timothy", 1, 7), ("psalms", 91, 1),
+    ("hebrews", 13, 5), ("1 john", 4, 8), ("john", 13, 34), ("psalms", 139, 14), ("matthew", 6, 34),
+    ("proverbs", 18, 10), ("psalms", 46, 1), ("matthew", 5, 9), ("colossians", 3, 12), ("romans", 5, 1),
+    ("ephesians", 3, 20), ("1 corinthians", 13, 13), ("galatians", 6, 9), ("psalms", 121, 1), ("psalms", 121, 2),
+    ("exodus", 20, 3), ("numbers", 6, 24), ("isaiah", 9, 6), ("luke", 2, 11), ("john", 8, 32),
+    ("john", 1, 14), ("acts", 2, 38), ("acts", 4, 12), ("romans", 12, 12), ("1 corinthians", 15, 58),
+    ("psalms", 51, 10), ("psalms", 118, 24), ("proverbs", 16, 3), ("zephaniah", 3, 17), ("hebrews", 11, 6),
+    ("james", 4, 7), ("1 peter", 3, 15), ("1 john", 4, 19), ("revelation", 21, 4), ("revelation", 3, 20),
+    ("matthew", 19, 26), ("mark", 10, 45), ("luke", 1, 37), ("john", 3, 17), ("john", 14, 1),
+    ("john", 15, 5), ("romans", 8, 18), ("romans", 8, 31), ("2 corinthians", 4, 18), ("ephesians", 6, 10),
+    ("philippians", 1, 6), ("philippians", 4, 8), ("colossians", 3, 2), ("1 thessalonians", 5, 16), ("1 thessalonians", 5, 17),
+    ("2 timothy", 4, 7), ("titus", 3, 5), ("hebrews", 10, 24), ("james", 1, 17), ("1 peter", 2, 9),
+    ("1 john", 4, 18), ("psalms", 23, 4), ("psalms", 19, 14), ("psalms", 100, 4), ("proverbs", 4, 23),
+    ("ecclesiastes", 3, 1), ("isaiah", 43, 2), ("isaiah", 55, 8), ("jeremiah", 33, 3), ("joshua", 24, 15),
+    ("deuteronomy", 6, 5), ("genesis", 1, 27), ("genesis", 50, 20), ("exodus", 14, 14), ("nahum", 1, 7),
+    ("habakkuk", 3, 19), ("malachi", 3, 10), ("matthew", 5, 44), ("matthew", 7, 12), ("john", 11, 35),
+    ("matthew", 5, 3), ("matthew", 5, 4), ("matthew", 5, 5), ("matthew", 5, 6), ("matthew", 5, 7),
+    ("matthew", 5, 8), ("matthew", 6, 9), ("matthew", 6, 10), ("matthew", 6, 11), ("matthew", 6, 12),
+    ("matthew", 6, 13), ("matthew", 6, 21), ("matthew", 6, 24), ("matthew", 7, 1), ("matthew", 7, 13),
+    ("matthew", 7, 14), ("matthew", 16, 24), ("matthew", 16, 26), ("matthew", 18, 20), ("matthew", 22, 39),
+    ("matthew", 25, 40), ("matthew", 26, 41), ("matthew", 4, 4), ("matthew", 5, 13), ("matthew", 5, 48),
+    ("matthew", 9, 37), ("matthew", 17, 20), ("matthew", 24, 35), ("mark", 11, 24), ("mark", 12, 30),
+    ("mark", 12, 31), ("mark", 16, 15), ("mark", 9, 23), ("mark", 8, 36), ("luke", 6, 31),
+    ("luke", 6, 38), ("luke", 9, 23), ("luke", 12, 34), ("luke", 19, 10), ("luke", 23, 34),
+    ("luke", 2, 14), ("luke", 15, 7), ("john", 1, 12), ("john", 1, 29), ("john", 3, 3),
+    ("john", 3, 30), ("john", 4, 24), ("john", 5, 24), ("john", 6, 35), ("john", 8, 12),
+    ("john", 10, 11), ("john", 10, 27), ("john", 10, 28), ("john", 11, 26), ("john", 13, 35),
+    ("john", 14, 2), ("john", 14, 3), ("john", 14, 15), ("john", 14, 26), ("john", 15, 12),
+    ("john", 16, 13), ("john", 17, 17), ("john", 20, 29), ("john", 20, 31), ("acts", 2, 42),
+    ("acts", 16, 31), ("acts", 17, 28), ("acts", 20, 35), ("romans", 1, 20), ("romans", 3, 24),
+    ("romans", 5, 3), ("romans", 5, 5), ("romans", 6, 4), ("romans", 8, 26), ("romans", 8, 37),
+    ("romans", 10, 13), ("romans", 10, 17), ("romans", 12, 10), ("romans", 12, 21), ("romans", 13, 8),
+    ("romans", 14, 8), ("1 corinthians", 2, 9), ("1 corinthians", 6, 19), ("1 corinthians", 10, 31), ("1 corinthians", 13, 5),
+    ("1 corinthians", 13, 6), ("1 corinthians", 13, 7), ("1 corinthians", 13, 8), ("1 corinthians", 15, 3), ("1 corinthians", 15, 55),
+    ("1 corinthians", 16, 14), ("2 corinthians", 5, 7), ("2 corinthians", 5, 21), ("2 corinthians", 9, 7), ("2 corinthians", 10, 5),
+    ("galatians", 5, 1), ("galatians", 5, 13), ("galatians", 5, 23), ("galatians", 6, 2), ("galatians", 6, 7),
+    ("ephesians", 1, 7), ("ephesians", 2, 10), ("ephesians", 4, 2), ("ephesians", 4, 26), ("ephesians", 4, 29),
+    ("ephesians", 5, 25), ("ephesians", 6, 1), ("ephesians", 6, 11), ("philippians", 1, 21), ("philippians", 2, 3),
+    ("philippians", 2, 5), ("philippians", 2, 10), ("philippians", 3, 14), ("philippians", 4, 4), ("philippians", 4, 19),
+    ("colossians", 1, 16), ("colossians", 3, 13), ("colossians", 3, 15), ("colossians", 3, 17), ("1 thessalonians", 4, 16),
+    ("2 thessalonians", 3, 3), ("1 timothy", 4, 12), ("1 timothy", 6, 10), ("1 timothy", 6, 12), ("2 timothy", 2, 15),
+    ("2 timothy", 3, 17), ("hebrews", 4, 16), ("hebrews", 11, 3), ("hebrews", 12, 1), ("hebrews", 13, 8),
+    ("james", 1, 12), ("james", 1, 19), ("james", 1, 22), ("james", 2, 17), ("james", 5, 16),
+    ("1 peter", 1, 3), ("1 peter", 4, 8), ("1 peter", 5, 8), ("2 peter", 3, 9), ("1 john", 3, 1),
+    ("1 john", 3, 18), ("1 john", 4, 7), ("1 john", 5, 14), ("jude", 1, 24), ("revelation", 1, 8),
+    ("revelation", 22, 13), ("revelation", 4, 11), ("genesis", 1, 3), ("genesis", 2, 24), ("genesis", 3, 15),
+    ("genesis", 12, 2), ("genesis", 28, 15), ("exodus", 3, 14), ("exodus", 15, 2), ("exodus", 20, 12),
+    ("leviticus", 19, 18), ("deuteronomy", 6, 4), ("deuteronomy", 7, 9), ("deuteronomy", 31, 8), ("joshua", 1, 8),
+    ("ruth", 1, 16), ("1 samuel", 16, 7), ("2 samuel", 22, 31), ("1 chronicles", 16, 34), ("nehemiah", 8, 10),
+    ("esther", 4, 14), ("job", 19, 25), ("psalms", 1, 1), ("psalms", 1, 2), ("psalms", 16, 11),
+    ("psalms", 18, 2), ("psalms", 23, 2), ("psalms", 23, 3), ("psalms", 23, 6), ("psalms", 27, 14),
+    ("psalms", 30, 5), ("psalms", 32, 8), ("psalms", 34, 18), ("psalms", 37, 5), ("psalms", 40, 1),
+    ("psalms", 42, 11), ("psalms", 55, 22), ("psalms", 56, 3), ("psalms", 62, 1), ("psalms", 73, 26),
+    ("psalms", 84, 11), ("psalms", 90, 12), ("psalms", 91, 11), ("psalms", 103, 12), ("psalms", 107, 1),
+    ("psalms", 119, 11), ("psalms", 127, 1), ("psalms", 133, 1), ("psalms", 145, 18), ("psalms", 147, 3),
+    ("psalms", 150, 6), ("proverbs", 1, 7), ("proverbs", 3, 9), ("proverbs", 9, 10), ("proverbs", 11, 25),
+    ("proverbs", 12, 25), ("proverbs", 15, 1), ("proverbs", 16, 9), ("proverbs", 17, 17), ("proverbs", 19, 21),
+    ("proverbs", 27, 17), ("proverbs", 31, 25), ("proverbs", 31, 30), ("ecclesiastes", 4, 9), ("ecclesiastes", 12, 13),
+    ("isaiah", 6, 8), ("isaiah", 7, 14), ("isaiah", 30, 18), ("isaiah", 40, 8), ("isaiah", 40, 29),
+    ("isaiah", 53, 6), ("isaiah", 54, 17), ("isaiah", 55, 11), ("isaiah", 58, 11), ("isaiah", 61, 1),
+    ("jeremiah", 1, 5), ("jeremiah", 17, 7), ("jeremiah", 31, 3), ("ezekiel", 36, 26), ("daniel", 3, 17),
+    ("hosea", 6, 6), ("joel", 2, 28), ("amos", 5, 24), ("habakkuk", 2, 3), ("zechariah", 4, 6),
+];
+
+/// Get how well known a verse is, from 1 (the most famous) to the length of `POPULAR_VERSES`
+/// Ranges get the best rank of any verse they cover, and books are matched by their canonical
+/// name (so `psalm` matches `psalms`)
+/// Returns `None` if the verse is not in `POPULAR_VERSES`
+/// # Example
+/// ```
+/// use bible_lib::{BibleLookup, popularity::popularity_rank};
+///
+/// assert_eq!(popularity_rank(&BibleLookup::new("John", 3, 16)), Some(1));
+/// assert_eq!(popularity_rank(&BibleLookup::new("1 Chronicles", 1, 1)), None);
+///
+/// // sort verses so the most familiar come first
+/// let mut verses = vec![BibleLookup::new("Numbers", 7, 12), BibleLookup::new("Psalm", 23, 1)];
+/// verses.sort_by_key(|verse| popularity_rank(verse).unwrap_or(u32::MAX));
+/// assert_eq!(verses[0], BibleLookup::new("Psalm", 23, 1));
+/// ```
+pub fn popularity_rank(lookup: &BibleLookup) -> Option<u32> {
+    let book = books::canonical_index(&lookup.book)?;
+    let (start, end) = lookup.bounds();
+    POPULAR_VERSES.iter()
+        .position(|(popular_book, chapter, verse)| {
+            books::canonical_index(popular_book) == Some(book) && start <= (*chapter, *verse) && (*chapter, *verse) <= end
+        })
+        .map(|index| index as u32 + 1)
+}
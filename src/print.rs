@@ -0,0 +1,33 @@
+/// Options for rendering a whole book with `Bible::book_to_print`
+/// The default prints plain verse numbers, one paragraph per block, with headings like `Chapter 3`
+/// # Example
+/// ```
+/// use bible_lib::print::PrintStyle;
+///
+/// // print each verse on its own line with superscript numbers and short headings
+/// let style = PrintStyle {
+///     superscripts: true,
+///     line_per_verse: true,
+///     chapter_heading: "— {} —".to_string(),
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrintStyle {
+    /// Write verse numbers as superscripts (i.e. `¹⁶For God...`) instead of plain numbers (i.e. `16 For God...`)
+    pub superscripts: bool,
+    /// Put each verse on its own line instead of joining the verses of each paragraph
+    /// (see `Bible::chapter_paragraphs`)
+    pub line_per_verse: bool,
+    /// The heading written before each chapter, with `{}` replaced by the chapter number
+    pub chapter_heading: String,
+}
+
+impl Default for PrintStyle {
+    fn default() -> Self {
+        Self {
+            superscripts: false,
+            line_per_verse: false,
+            chapter_heading: "Chapter {}".to_string(),
+        }
+    }
+}
@@ -0,0 +1,184 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::BibleLookup;
+
+/// Options for searching the text of a Bible
+/// The default is a case-insensitive search with exact matching of accented characters
+/// # Example
+/// ```
+/// use bible_lib::search::SearchOptions;
+///
+/// // search with exact casing
+/// let options = SearchOptions {
+///     case_sensitive: true,
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchOptions {
+    /// Match the casing of the query exactly
+    pub case_sensitive: bool,
+    /// Ignore accents and other diacritics when comparing (i.e. `Jesus` matches `Jesús`)
+    /// Mostly useful for non-English custom translations
+    /// Requires the `diacritics` feature to be enabled
+    #[cfg(feature = "diacritics")]
+    pub fold_diacritics: bool,
+}
+
+impl SearchOptions {
+    /// Normalize text according to these options so it can be compared
+    #[doc(hidden)]
+    pub(crate) fn normalize(&self, text: &str) -> String {
+        #[cfg(feature = "diacritics")]
+        let text = if self.fold_diacritics { fold_diacritics(text) } else { text.to_string() };
+        #[cfg(not(feature = "diacritics"))]
+        let text = text.to_string();
+
+        if self.case_sensitive {
+            text
+        } else {
+            text.to_lowercase()
+        }
+    }
+}
+
+/// Remove diacritics from a string (i.e. `Jesús` becomes `Jesus`)
+/// The string is decomposed (NFD) and the combining marks are removed
+/// Requires the `diacritics` feature to be enabled
+/// # Example
+/// ```
+/// use bible_lib::search::fold_diacritics;
+///
+/// assert_eq!(fold_diacritics("Jesús"), "Jesus");
+/// ```
+#[cfg(feature = "diacritics")]
+pub fn fold_diacritics(text: &str) -> String {
+    use unicode_normalization::{UnicodeNormalization, char::is_combining_mark};
+    text.nfd().filter(|c| !is_combining_mark(*c)).collect()
+}
+
+/// Split text into lowercase word tokens, removing punctuation
+/// Words are split on any character that is not alphanumeric
+/// # Example
+/// ```
+/// use bible_lib::search::tokenize;
+///
+/// assert_eq!(tokenize("Jesus wept."), vec!["jesus", "wept"]);
+/// ```
+pub fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_lowercase())
+        .collect()
+}
+
+/// Common words skipped by `Bible::word_frequencies`, as they would otherwise dominate every count
+/// Includes the archaic forms used by older translations (i.e. `thee` and `hath`)
+pub const STOPWORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "but", "by", "for", "from", "had", "has", "have",
+    "he", "her", "him", "his", "i", "in", "is", "it", "me", "my", "not", "of", "on", "or", "our",
+    "shall", "she", "so", "that", "the", "their", "them", "then", "there", "they", "this", "to",
+    "unto", "upon", "us", "was", "we", "were", "which", "who", "will", "with", "ye", "you", "your",
+    "hath", "thee", "thou", "thy", "thine", "saith",
+];
+
+/// The Jaccard similarity between a set of words and the words of `text`
+/// (the number of shared words divided by the number of distinct words in either)
+#[doc(hidden)]
+pub(crate) fn word_similarity(words: &HashSet<String>, text: &str) -> f64 {
+    let other = tokenize(text).into_iter().collect::<HashSet<String>>();
+    let union = words.union(&other).count();
+    if union == 0 {
+        return 0.0;
+    }
+    words.intersection(&other).count() as f64 / union as f64
+}
+
+/// An inverted index from each word to the verses containing it
+/// Build it once with `Bible::build_index` and reuse it for fast repeated searches
+/// # Example
+/// ```
+/// use bible_lib::{Bible, Translation};
+///
+/// // get the bible translation
+/// let bible = Bible::new(Translation::default()).unwrap();
+/// // build the index
+/// let index = bible.build_index();
+///
+/// // find every verse containing "shepherd"
+/// for verse in index.search("shepherd") {
+///     println!("{}", verse);
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct WordIndex {
+    pub(crate) words: HashMap<String, Vec<BibleLookup>>,
+}
+
+impl WordIndex {
+    /// Get the verses containing `word` in canonical order
+    /// `word` is not case-sensitive
+    /// Returns an empty slice if the word does not appear in the Bible
+    pub fn search(&self, word: &str) -> &[BibleLookup] {
+        self.words.get(&word.to_lowercase()).map(|verses| verses.as_slice()).unwrap_or(&[])
+    }
+}
+
+/// Find the byte ranges of every (non-overlapping) case-insensitive occurrence of `term` in `text`
+/// The ranges always fall on character boundaries of `text`
+#[doc(hidden)]
+pub(crate) fn find_ignore_case(text: &str, term: &str) -> Vec<std::ops::Range<usize>> {
+    let term = term.to_lowercase();
+    let mut ranges = Vec::new();
+    if term.is_empty() {
+        return ranges;
+    }
+
+    let mut start = 0;
+    while let Some(c) = text[start..].chars().next() {
+        match match_length(&text[start..], &term) {
+            Some(length) => {
+                ranges.push(start..start + length);
+                start += length;
+            }
+            None => start += c.len_utf8(),
+        }
+    }
+    ranges
+}
+
+/// Get the byte length of the match if `text` starts with the lowercase `term` (ignoring case)
+#[doc(hidden)]
+fn match_length(text: &str, term: &str) -> Option<usize> {
+    let mut expected = term.chars().peekable();
+    for (index, c) in text.char_indices() {
+        for lower in c.to_lowercase() {
+            if expected.next() != Some(lower) {
+                return None;
+            }
+        }
+        if expected.peek().is_none() {
+            return Some(index + c.len_utf8());
+        }
+    }
+    None
+}
+
+/// Get the smallest edit (Levenshtein) distance between `query` and any substring of `text`
+/// The match may start and end anywhere in `text` at no cost
+#[doc(hidden)]
+pub(crate) fn substring_distance(query: &[char], text: &[char]) -> usize {
+    // distances for matching the first `i` characters of the query, ending at the current text position
+    let mut previous = (0..=query.len()).collect::<Vec<usize>>();
+    let mut best = previous[query.len()];
+    for t in text {
+        let mut current = vec![0; query.len() + 1];
+        for (i, q) in query.iter().enumerate() {
+            let substitution = previous[i] + usize::from(q != t);
+            current[i + 1] = substitution.min(previous[i + 1] + 1).min(current[i] + 1);
+        }
+        best = best.min(current[query.len()]);
+        previous = current;
+    }
+    best
+}